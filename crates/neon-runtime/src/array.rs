@@ -12,4 +12,41 @@ extern "C" {
     #[link_name = "Neon_Array_Length"]
     pub fn len(array: Local) -> u32;
 
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing the
+    /// `len` numbers starting at `data`. Returns `false` if the array couldn't be filled.
+    #[link_name = "Neon_Array_FromF64Slice"]
+    pub fn from_f64_slice(out: &mut Local, isolate: *mut Isolate, data: *const f64, len: u32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing the
+    /// `len` signed 32-bit integers starting at `data`. Returns `false` if the array couldn't be
+    /// filled.
+    #[link_name = "Neon_Array_FromI32Slice"]
+    pub fn from_i32_slice(out: &mut Local, isolate: *mut Isolate, data: *const i32, len: u32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing the
+    /// `len` unsigned 32-bit integers starting at `data`. Returns `false` if the array couldn't be
+    /// filled.
+    #[link_name = "Neon_Array_FromU32Slice"]
+    pub fn from_u32_slice(out: &mut Local, isolate: *mut Isolate, data: *const u32, len: u32) -> bool;
+
+    /// Copies the first `len` elements of a `v8::Array` to `out`. Returns `false` if reading an
+    /// element threw an exception, or if an element is not a number. In the second case,
+    /// nothing is thrown, and the element's index and value are written to `index` and `element`.
+    #[link_name = "Neon_Array_ToF64Slice"]
+    pub fn to_f64_slice(out: *mut f64, index: &mut u32, element: &mut Local, array: Local, len: u32) -> bool;
+
+    /// Copies the first `len` elements of a `v8::Array` to `out`. Returns `false` if reading an
+    /// element threw an exception, or if an element is not a signed 32-bit integer. In the second
+    /// case, nothing is thrown, and the element's index and value are written to `index` and
+    /// `element`.
+    #[link_name = "Neon_Array_ToI32Slice"]
+    pub fn to_i32_slice(out: *mut i32, index: &mut u32, element: &mut Local, array: Local, len: u32) -> bool;
+
+    /// Copies the first `len` elements of a `v8::Array` to `out`. Returns `false` if reading an
+    /// element threw an exception, or if an element is not an unsigned 32-bit integer. In the second
+    /// case, nothing is thrown, and the element's index and value are written to `index` and
+    /// `element`.
+    #[link_name = "Neon_Array_ToU32Slice"]
+    pub fn to_u32_slice(out: *mut u32, index: &mut u32, element: &mut Local, array: Local, len: u32) -> bool;
+
 }
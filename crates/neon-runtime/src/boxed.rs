@@ -0,0 +1,18 @@
+//! Facilities for wrapping Rust data in opaque JavaScript objects.
+
+use std::os::raw::c_void;
+use raw::{Isolate, Local};
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to a newly created opaque object that owns
+    /// `data`. When the object is garbage collected, `drop` is called with `data`. Returns `false`
+    /// if the object couldn't be created.
+    #[link_name = "Neon_Box_New"]
+    pub fn new(out: &mut Local, isolate: *mut Isolate, data: *mut c_void, drop: extern "C" fn(*mut c_void)) -> bool;
+
+    /// Gets the data owned by an object created with `Neon_Box_New`.
+    #[link_name = "Neon_Box_Get"]
+    pub fn get(obj: Local) -> *mut c_void;
+
+}
@@ -37,6 +37,12 @@ extern "C" {
     #[link_name = "Neon_Call_IsConstruct"]
     pub fn is_construct(info: &FunctionCallbackInfo) -> bool;
 
+    /// Mutates the `out` argument provided to refer to the name of the function being called,
+    /// as recorded in its data value, or else the name of the constructor for a constructor
+    /// call. Returns `false` if the function has no name.
+    #[link_name = "Neon_Call_CalleeName"]
+    pub fn callee_name(info: &FunctionCallbackInfo, out: &mut Local) -> bool;
+
     /// Mutates the `out` argument provided to refer to the `v8::Local` handle value of the object
     /// the function is bound to.
     #[link_name = "Neon_Call_This"]
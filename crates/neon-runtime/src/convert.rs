@@ -2,6 +2,17 @@
 
 use raw::Local;
 
+/// The preferred type passed to the JavaScript `ToPrimitive` conversion.
+///
+/// The corresponding C++ enum `Neon_PrimitiveHint` is defined in neon.h.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PrimitiveHint {
+    Default,
+    Number,
+    String
+}
+
 extern "C" {
 
     /// Casts the value provided to a `v8::Object` and mutates the `out` argument provided to refer
@@ -16,4 +27,40 @@ extern "C" {
     #[link_name = "Neon_Convert_ToString"]
     pub fn to_string(out: &mut Local, value: Local) -> bool;
 
+    /// Casts the value provided to a `v8::Number` and mutates the `out` argument provided to refer
+    /// to `v8::Local` handle of the converted value. Returns `false` if the conversion didn't
+    /// succeed.
+    #[link_name = "Neon_Convert_ToNumber"]
+    pub fn to_number(out: &mut Local, value: Local) -> bool;
+
+    /// Casts the value provided to a `v8::Boolean` and mutates the `out` argument provided to refer
+    /// to `v8::Local` handle of the converted value. Returns `false` if the conversion didn't
+    /// succeed.
+    #[link_name = "Neon_Convert_ToBoolean"]
+    pub fn to_boolean(out: &mut Local, value: Local) -> bool;
+
+    /// Casts the value provided to a `v8::Integer` and mutates the `out` argument provided to refer
+    /// to `v8::Local` handle of the converted value. Returns `false` if the conversion didn't
+    /// succeed.
+    #[link_name = "Neon_Convert_ToInteger"]
+    pub fn to_integer(out: &mut Local, value: Local) -> bool;
+
+    /// Casts the value provided to a `v8::Int32` and mutates the `out` argument provided to refer
+    /// to `v8::Local` handle of the converted value. Returns `false` if the conversion didn't
+    /// succeed.
+    #[link_name = "Neon_Convert_ToInt32"]
+    pub fn to_int32(out: &mut Local, value: Local) -> bool;
+
+    /// Casts the value provided to a `v8::Uint32` and mutates the `out` argument provided to refer
+    /// to `v8::Local` handle of the converted value. Returns `false` if the conversion didn't
+    /// succeed.
+    #[link_name = "Neon_Convert_ToUint32"]
+    pub fn to_uint32(out: &mut Local, value: Local) -> bool;
+
+    /// Converts the value provided to a primitive value, using the `hint` as the preferred type
+    /// for objects, and mutates the `out` argument provided to refer to the result. Returns
+    /// `false` if the conversion didn't succeed.
+    #[link_name = "Neon_Convert_ToPrimitive"]
+    pub fn to_primitive(out: &mut Local, value: Local, hint: PrimitiveHint) -> bool;
+
 }
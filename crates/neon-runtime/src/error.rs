@@ -1,5 +1,6 @@
 //! Facilities for creating and throwing JS errors.
 
+use std::os::raw::c_void;
 use raw::Local;
 
 extern "C" {
@@ -8,6 +9,12 @@ extern "C" {
     #[link_name = "Neon_Error_Throw"]
     pub fn throw(val: Local);
 
+    /// Calls `callback` with `data` inside a `v8::TryCatch`. If the callback returns `false` and
+    /// an exception was thrown, the exception is caught, `out` is mutated to refer to it, and the
+    /// function returns `true`.
+    #[link_name = "Neon_Error_TryCatch"]
+    pub fn try_catch(out: &mut Local, callback: extern "C" fn(*mut c_void) -> bool, data: *mut c_void) -> bool;
+
     /// Mutates the `out` argument provided to refer to a newly created `Error` object.
     #[link_name = "Neon_Error_NewError"]
     pub fn new_error(out: &mut Local, msg: Local);
@@ -11,14 +11,27 @@ extern "C" {
     #[link_name = "Neon_Fun_New"]
     pub fn new(out: &mut Local, isolate: *mut c_void, callback: CCallback) -> bool;
 
-    /// Mutates the `out` argument provided to refer to a newly created `v8::FunctionTemplate`.
-    /// Returns `false` if the value couldn't be created.
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Function` that calls
+    /// the static `callback` with `data` as its data value. Returns `false` if the value couldn't
+    /// be created.
+    #[link_name = "Neon_Fun_NewWithData"]
+    pub fn new_with_data(out: &mut Local, isolate: *mut c_void, callback: *mut c_void, data: Local) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::FunctionTemplate`,
+    /// whose functions are named `name` in argument errors. Returns `false` if the value couldn't
+    /// be created.
     #[link_name = "Neon_Fun_Template_New"]
-    pub fn new_template(out: &mut Local, isolate: *mut c_void, callback: CCallback) -> bool;
+    pub fn new_template(out: &mut Local, isolate: *mut c_void, callback: CCallback, name: Local) -> bool;
 
-    /// Gets the reference to the `v8::Local<v8::External>` handle provided.
+    /// Gets the dynamic callback from the data value of a function created by `new` or
+    /// `new_template`.
     #[link_name = "Neon_Fun_GetDynamicCallback"]
-    pub fn get_dynamic_callback(obj: Local) -> *mut c_void;
+    pub fn get_dynamic_callback(data: Local) -> *mut c_void;
+
+    /// Sets the name of the function provided, which is reported by its `name` property and
+    /// in stack traces.
+    #[link_name = "Neon_Fun_SetName"]
+    pub fn set_name(fun: Local, name: Local);
 
     /// Calls the function provided (`fun`) and mutates the `out` argument provided to refer to the
     /// result of the function call. Returns `false` if the result of the call was empty.
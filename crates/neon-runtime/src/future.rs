@@ -0,0 +1,24 @@
+//! Facilities for settling promises when Rust futures complete on other threads.
+
+use raw::Local;
+use std::os::raw::c_void;
+
+extern "C" {
+
+    /// Creates a pending `v8::Promise`, mutating the `out` argument provided to refer to it and
+    /// `out_handle` to refer to a handle for completing it. Once `send` is called with the
+    /// handle, `complete` is called on the main thread with `future`; the promise is resolved
+    /// with the value it produces, or rejected with the exception it throws. Returns `false` if
+    /// the promise couldn't be created.
+    #[link_name = "Neon_Future_New"]
+    pub fn new(out: &mut Local,
+               out_handle: &mut *mut c_void,
+               future: *mut c_void,
+               complete: unsafe extern "C" fn(*mut c_void, &mut Local)) -> bool;
+
+    /// Schedules the completion of a promise created by `new`. This can be called from any
+    /// thread, but must be called exactly once for each handle.
+    #[link_name = "Neon_Future_Send"]
+    pub fn send(handle: *mut c_void);
+
+}
@@ -0,0 +1,34 @@
+//! Facilities for driving the JS iteration protocol.
+
+use raw::{Isolate, Local};
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to the well-known `Symbol.iterator` symbol.
+    #[link_name = "Neon_Iterator_Symbol"]
+    pub fn symbol(out: &mut Local, isolate: *mut Isolate);
+
+    /// Mutates the `iterator` and `next` arguments provided to refer to the iterator produced by
+    /// calling `iterable[Symbol.iterator]()` and to its `next` method. Returns `false` and throws
+    /// a `TypeError` if the value isn't iterable.
+    #[link_name = "Neon_Iterator_Get"]
+    pub fn get(iterator: &mut Local, next: &mut Local, iterable: Local) -> bool;
+
+    /// Calls the `next` method of an iterator, mutating `out_done` to indicate whether the
+    /// iterator is finished and, if it isn't, `out_value` to refer to the value it produced.
+    /// Returns `false` if the call threw or didn't produce an iterator result object.
+    #[link_name = "Neon_Iterator_Next"]
+    pub fn next(out_value: &mut Local, out_done: &mut bool, iterator: Local, next: Local) -> bool;
+
+    /// Calls the `return` method of an iterator, if it has one, to let it clean up after the
+    /// iteration stopped early. Returns `false` if the call threw.
+    #[link_name = "Neon_Iterator_Close"]
+    pub fn close(iterator: Local) -> bool;
+
+    /// Calls the `return` method of an iterator, if it has one, after the iteration stopped
+    /// because of an exception. Any exception thrown by the call is discarded, so that the
+    /// original exception can be rethrown.
+    #[link_name = "Neon_Iterator_CloseAfterThrow"]
+    pub fn close_after_throw(iterator: Local);
+
+}
@@ -0,0 +1,18 @@
+//! Facilities for converting between values and JSON text.
+
+use raw::Local;
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to the value described by a JSON
+    /// `v8::String`. Returns `false` and throws a `SyntaxError` if the text isn't valid JSON.
+    #[link_name = "Neon_JSON_Parse"]
+    pub fn parse(out: &mut Local, text: Local) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a `v8::String` containing the JSON text
+    /// for `value`, indented by `gap` if it's not empty. Returns `false` if the value couldn't be
+    /// converted, which throws a `TypeError` for values JSON can't represent.
+    #[link_name = "Neon_JSON_Stringify"]
+    pub fn stringify(out: &mut Local, value: Local, gap: Local) -> bool;
+
+}
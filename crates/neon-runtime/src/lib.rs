@@ -3,7 +3,11 @@ pub mod call;
 pub mod scope;
 pub mod object;
 pub mod array;
+pub mod map;
+pub mod set;
+pub mod promise;
 pub mod string;
+pub mod regexp;
 pub mod primitive;
 pub mod error;
 pub mod arraybuffer;
@@ -11,7 +15,13 @@ pub mod buffer;
 pub mod tag;
 pub mod module;
 pub mod mem;
+pub mod value;
 pub mod fun;
 pub mod convert;
+pub mod json;
+pub mod iterator;
+pub mod serialize;
 pub mod class;
+pub mod boxed;
 pub mod task;
+pub mod future;
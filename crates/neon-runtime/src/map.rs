@@ -0,0 +1,43 @@
+//! Facilities for working with `v8::Map`s.
+
+use raw::{Local, Isolate};
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Map`.
+    #[link_name = "Neon_Map_New"]
+    pub fn new(out: &mut Local, isolate: *mut Isolate);
+
+    /// Gets the number of entries in a `v8::Map`.
+    #[link_name = "Neon_Map_Size"]
+    pub fn size(map: Local) -> u32;
+
+    /// Mutates the `out` argument provided to refer to the value associated with `key` in the
+    /// `v8::Map`, or `undefined` if there is no such entry. Returns `false` if the lookup threw.
+    #[link_name = "Neon_Map_Get"]
+    pub fn get(out: &mut Local, map: Local, key: Local) -> bool;
+
+    /// Associates `val` with `key` in the `v8::Map`. Returns `false` if the operation threw.
+    #[link_name = "Neon_Map_Set"]
+    pub fn set(map: Local, key: Local, val: Local) -> bool;
+
+    /// Mutates the `out` argument provided to indicate whether the `v8::Map` has an entry for
+    /// `key`. Returns `false` if the operation threw.
+    #[link_name = "Neon_Map_Has"]
+    pub fn has(out: &mut bool, map: Local, key: Local) -> bool;
+
+    /// Removes the entry for `key` from the `v8::Map`, mutating the `out` argument provided to
+    /// indicate whether an entry was removed. Returns `false` if the operation threw.
+    #[link_name = "Neon_Map_Delete"]
+    pub fn delete(out: &mut bool, map: Local, key: Local) -> bool;
+
+    /// Removes all entries from the `v8::Map`.
+    #[link_name = "Neon_Map_Clear"]
+    pub fn clear(map: Local);
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing
+    /// the entries of the `v8::Map`, flattened as `[key0, value0, key1, value1, ...]`.
+    #[link_name = "Neon_Map_AsArray"]
+    pub fn as_array(out: &mut Local, map: Local);
+
+}
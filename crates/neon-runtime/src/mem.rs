@@ -1,4 +1,5 @@
-//! A helper function for comparing `v8::Local` handles.
+//! Helper functions for comparing `v8::Local` handles and managing `v8::Persistent` handles.
+use std::os::raw::c_void;
 use raw::Local;
 
 extern "C" {
@@ -7,4 +8,17 @@ extern "C" {
     #[link_name = "Neon_Mem_SameHandle"]
     pub fn same_handle(h1: Local, h2: Local) -> bool;
 
+    /// Creates a new `v8::Persistent` handle referring to the same value as a `v8::Local`
+    /// handle, keeping the value alive until the persistent handle is dropped.
+    #[link_name = "Neon_Mem_NewPersistent"]
+    pub fn new_persistent(h: Local) -> *mut c_void;
+
+    /// Mutates the `out` argument provided to refer to the value of a `v8::Persistent` handle.
+    #[link_name = "Neon_Mem_ReadPersistent"]
+    pub fn read_persistent(out: &mut Local, persistent: *mut c_void);
+
+    /// Releases a `v8::Persistent` handle created by `new_persistent`.
+    #[link_name = "Neon_Mem_DropPersistent"]
+    pub fn drop_persistent(persistent: *mut c_void);
+
 }
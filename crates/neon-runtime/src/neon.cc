@@ -2,12 +2,15 @@
 #include <nan.h>
 #include <stdint.h>
 #include <stdio.h>
+#include <string>
 #include "node.h"
 #include "node_version.h"
 #include "neon.h"
 #include "neon_string.h"
 #include "neon_class_metadata.h"
 #include "neon_task.h"
+#include "neon_future.h"
+#include "neon_serialize.h"
 
 extern "C" void Neon_Call_SetReturn(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::Value> value) {
   info->GetReturnValue().Set(value);
@@ -25,6 +28,28 @@ extern "C" bool Neon_Call_IsConstruct(v8::FunctionCallbackInfo<v8::Value> *info)
   return info->IsConstructCall();
 }
 
+extern "C" bool Neon_Call_CalleeName(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::String> *out) {
+  v8::Local<v8::Value> name;
+  v8::Local<v8::Value> data = info->Data();
+  if (data->IsArray() && data.As<v8::Array>()->Length() == 2) {
+    if (!Nan::Get(data.As<v8::Array>(), 1).ToLocal(&name)) {
+      return false;
+    }
+  } else if (info->IsConstructCall()) {
+    // Class constructors share their data with the class metadata, so they're named by the
+    // constructor being called.
+    name = info->NewTarget();
+  }
+  if (!name.IsEmpty() && name->IsFunction()) {
+    name = name.As<v8::Function>()->GetName();
+  }
+  if (name.IsEmpty() || !name->IsString() || name.As<v8::String>()->Length() == 0) {
+    return false;
+  }
+  *out = name.As<v8::String>();
+  return true;
+}
+
 extern "C" void Neon_Call_This(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::Object> *out) {
   *out = info->This();
 }
@@ -56,6 +81,44 @@ extern "C" bool Neon_Object_GetOwnPropertyNames(v8::Local<v8::Array> *out, v8::L
   return maybe.ToLocal(out);
 }
 
+extern "C" bool Neon_Object_GetPropertyNames(v8::Local<v8::Array> *out, v8::Local<v8::Object> obj, bool own_only, bool enumerable_only, bool configurable_only, bool include_symbols, bool include_indices) {
+  int filter = v8::ALL_PROPERTIES;
+  if (enumerable_only) {
+    filter |= v8::ONLY_ENUMERABLE;
+  }
+  if (configurable_only) {
+    filter |= v8::ONLY_CONFIGURABLE;
+  }
+  if (!include_symbols) {
+    filter |= v8::SKIP_SYMBOLS;
+  }
+  v8::Local<v8::Context> context = Nan::GetCurrentContext();
+  v8::KeyCollectionMode mode = own_only ? v8::KeyCollectionMode::kOwnOnly : v8::KeyCollectionMode::kIncludePrototypes;
+  v8::IndexFilter index_filter = include_indices ? v8::IndexFilter::kIncludeIndices : v8::IndexFilter::kSkipIndices;
+#if V8_MAJOR_VERSION > 6 || (V8_MAJOR_VERSION == 6 && V8_MINOR_VERSION >= 8)
+  v8::MaybeLocal<v8::Array> maybe = obj->GetPropertyNames(context, mode, static_cast<v8::PropertyFilter>(filter), index_filter, v8::KeyConversionMode::kConvertToString);
+  return maybe.ToLocal(out);
+#else
+  // Older versions of V8 produce index keys as numbers, so convert them like `Object.keys` does.
+  v8::Local<v8::Array> names;
+  if (!obj->GetPropertyNames(context, mode, static_cast<v8::PropertyFilter>(filter), index_filter).ToLocal(&names)) {
+    return false;
+  }
+  for (uint32_t i = 0; i < names->Length(); i++) {
+    v8::Local<v8::Value> name;
+    v8::Local<v8::String> key;
+    if (!names->Get(context, i).ToLocal(&name)) {
+      return false;
+    }
+    if (name->IsNumber() && (!name->ToString(context).ToLocal(&key) || names->Set(context, i, key).IsNothing())) {
+      return false;
+    }
+  }
+  *out = names;
+  return true;
+#endif
+}
+
 extern "C" void *Neon_Object_GetIsolate(v8::Local<v8::Object> obj) {
   return obj->GetIsolate();
 }
@@ -80,6 +143,14 @@ extern "C" void Neon_Primitive_Number(v8::Local<v8::Number> *out, v8::Isolate *i
   *out = v8::Number::New(isolate, value);
 }
 
+extern "C" void Neon_Primitive_Integer(v8::Local<v8::Integer> *out, v8::Isolate *isolate, int32_t value) {
+  *out = v8::Integer::New(isolate, value);
+}
+
+extern "C" void Neon_Primitive_Uint32(v8::Local<v8::Integer> *out, v8::Isolate *isolate, uint32_t value) {
+  *out = v8::Integer::NewFromUnsigned(isolate, value);
+}
+
 extern "C" double Neon_Primitive_NumberValue(v8::Local<v8::Number> n) {
   return n->Value();
 }
@@ -146,6 +217,216 @@ extern "C" bool Neon_Object_Set(bool *out, v8::Local<v8::Object> obj, v8::Local<
   return false;
 }
 
+// Converts a key to a property name, as the JS ToPropertyKey operation does.
+static bool Neon_Object_ToName(v8::Local<v8::Name> *out, v8::Local<v8::Value> key) {
+  if (key->IsName()) {
+    *out = key.As<v8::Name>();
+    return true;
+  }
+  v8::Local<v8::String> str;
+  if (!Nan::To<v8::String>(key).ToLocal(&str)) {
+    return false;
+  }
+  *out = str;
+  return true;
+}
+
+extern "C" bool Neon_Object_Has(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key) {
+  v8::Maybe<bool> maybe = obj->Has(Nan::GetCurrentContext(), key);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_HasOwn(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key) {
+  v8::Local<v8::Name> name;
+  if (!Neon_Object_ToName(&name, key)) {
+    return false;
+  }
+  v8::Maybe<bool> maybe = obj->HasOwnProperty(Nan::GetCurrentContext(), name);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_Delete(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key) {
+  v8::Maybe<bool> maybe = obj->Delete(Nan::GetCurrentContext(), key);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_DefineDataProperty(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key, v8::Local<v8::Value> val, bool writable, bool enumerable, bool configurable) {
+  v8::Local<v8::Name> name;
+  if (!Neon_Object_ToName(&name, key)) {
+    return false;
+  }
+  v8::PropertyDescriptor desc(val, writable);
+  desc.set_enumerable(enumerable);
+  desc.set_configurable(configurable);
+  v8::Maybe<bool> maybe = obj->DefineProperty(Nan::GetCurrentContext(), name, desc);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_DefineAccessorProperty(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key, v8::Local<v8::Value> getter, v8::Local<v8::Value> setter, bool enumerable, bool configurable) {
+  v8::Local<v8::Name> name;
+  if (!Neon_Object_ToName(&name, key)) {
+    return false;
+  }
+  v8::PropertyDescriptor desc(getter, setter);
+  desc.set_enumerable(enumerable);
+  desc.set_configurable(configurable);
+  v8::Maybe<bool> maybe = obj->DefineProperty(Nan::GetCurrentContext(), name, desc);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_GetOwnPropertyDescriptor(v8::Local<v8::Value> *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key) {
+  v8::Local<v8::Name> name;
+  if (!Neon_Object_ToName(&name, key)) {
+    return false;
+  }
+  return obj->GetOwnPropertyDescriptor(Nan::GetCurrentContext(), name).ToLocal(out);
+}
+
+extern "C" bool Neon_Object_Create(v8::Local<v8::Object> *out, v8::Local<v8::Value> proto) {
+  if (!proto->IsObject() && !proto->IsNull()) {
+    Nan::ThrowTypeError("Object prototype may only be an Object or null");
+    return false;
+  }
+  v8::Local<v8::Object> obj = Nan::New<v8::Object>();
+  Nan::Maybe<bool> maybe = Nan::SetPrototype(obj, proto);
+  if (maybe.IsNothing()) {
+    return false;
+  }
+  *out = obj;
+  return true;
+}
+
+extern "C" void Neon_Object_GetPrototype(v8::Local<v8::Value> *out, v8::Local<v8::Object> obj) {
+  *out = obj->GetPrototype();
+}
+
+extern "C" bool Neon_Object_SetPrototype(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> proto) {
+  if (!proto->IsObject() && !proto->IsNull()) {
+    Nan::ThrowTypeError("Object prototype may only be an Object or null");
+    return false;
+  }
+  Nan::Maybe<bool> maybe = Nan::SetPrototype(obj, proto);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_Freeze(bool *out, v8::Local<v8::Object> obj) {
+  v8::Maybe<bool> maybe = obj->SetIntegrityLevel(Nan::GetCurrentContext(), v8::IntegrityLevel::kFrozen);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Object_Seal(bool *out, v8::Local<v8::Object> obj) {
+  v8::Maybe<bool> maybe = obj->SetIntegrityLevel(Nan::GetCurrentContext(), v8::IntegrityLevel::kSealed);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+// V8 has no API for some integrity operations, so Neon calls the built-in functions instead.
+// They're captured into an object stored privately on each context's global object, when a
+// module is registered in that context, so user code can't replace them first.
+static const char *neon_builtins[][2] = {
+  { "Object", "isFrozen" },
+  { "Object", "isSealed" },
+  { "Object", "isExtensible" },
+  { "Reflect", "preventExtensions" }
+};
+
+static v8::Local<v8::Private> Neon_Object_BuiltinsKey(v8::Isolate *isolate) {
+  return v8::Private::ForApi(isolate, Nan::New("neon::builtins").ToLocalChecked());
+}
+
+extern "C" bool Neon_Object_CaptureBuiltins(v8::Isolate *isolate) {
+  Nan::HandleScope scope;
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Object> global = context->Global();
+  v8::Local<v8::Private> key = Neon_Object_BuiltinsKey(isolate);
+  v8::Maybe<bool> captured = global->HasPrivate(context, key);
+  if (captured.IsNothing() || captured.FromJust()) {
+    return captured.IsJust();
+  }
+
+  v8::Local<v8::Object> builtins = v8::Object::New(isolate);
+  if (builtins->SetPrototype(context, Nan::Null()).IsNothing()) {
+    return false;
+  }
+  for (size_t i = 0; i < sizeof(neon_builtins) / sizeof(neon_builtins[0]); i++) {
+    v8::Local<v8::Value> holder, function;
+    if (!Nan::Get(global, Nan::New(neon_builtins[i][0]).ToLocalChecked()).ToLocal(&holder)) {
+      return false;
+    }
+    if (!holder->IsObject()) {
+      continue;
+    }
+    v8::Local<v8::String> name = Nan::New(neon_builtins[i][1]).ToLocalChecked();
+    if (!Nan::Get(holder.As<v8::Object>(), name).ToLocal(&function)) {
+      return false;
+    }
+    if (function->IsFunction() && Nan::Set(builtins, name, function).IsNothing()) {
+      return false;
+    }
+  }
+  return global->SetPrivate(context, key, builtins).IsJust();
+}
+
+extern "C" bool Neon_Object_GetBuiltin(v8::Local<v8::Function> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len) {
+  // Contexts without a Neon module, such as those created by the `vm` module, capture the
+  // built-in functions the first time they're needed instead.
+  if (!Neon_Object_CaptureBuiltins(isolate)) {
+    return false;
+  }
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Value> builtins, function;
+  if (!context->Global()->GetPrivate(context, Neon_Object_BuiltinsKey(isolate)).ToLocal(&builtins)) {
+    return false;
+  }
+  v8::Local<v8::String> name;
+  if (!v8::String::NewFromUtf8(isolate, (const char*)data, v8::NewStringType::kNormal, len).ToLocal(&name)) {
+    return false;
+  }
+  if (builtins->IsObject() && !Nan::Get(builtins.As<v8::Object>(), name).ToLocal(&function)) {
+    return false;
+  }
+  if (function.IsEmpty() || !function->IsFunction()) {
+    std::string message = std::string((const char*)data, len) + " is not available as a built-in function";
+    Nan::ThrowTypeError(message.c_str());
+    return false;
+  }
+  *out = function.As<v8::Function>();
+  return true;
+}
+
+extern "C" bool Neon_Object_FromEntries(v8::Local<v8::Object> *out, v8::Isolate *isolate, const uint8_t **keys, const int32_t *key_lens, const v8::Local<v8::Value> *values, uint32_t len) {
+  Nan::EscapableHandleScope scope;
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Object> obj = v8::Object::New(isolate);
+  for (uint32_t i = 0; i < len; i++) {
+    v8::Local<v8::String> key;
+    if (!v8::String::NewFromUtf8(isolate, (const char*)keys[i], v8::NewStringType::kInternalized, key_lens[i]).ToLocal(&key)) {
+      return false;
+    }
+    if (obj->CreateDataProperty(context, key, values[i]).IsNothing()) {
+      return false;
+    }
+  }
+  *out = scope.Escape(obj);
+  return true;
+}
+
+extern "C" bool Neon_Object_FromKeys(v8::Local<v8::Object> *out, v8::Isolate *isolate, v8::Local<v8::Array> keys, const v8::Local<v8::Value> *values, uint32_t len) {
+  Nan::EscapableHandleScope scope;
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Object> obj = v8::Object::New(isolate);
+  for (uint32_t i = 0; i < len; i++) {
+    v8::Local<v8::Value> key;
+    if (!keys->Get(context, i).ToLocal(&key)) {
+      return false;
+    }
+    if (obj->CreateDataProperty(context, key.As<v8::Name>(), values[i]).IsNothing()) {
+      return false;
+    }
+  }
+  *out = scope.Escape(obj);
+  return true;
+}
+
 extern "C" void Neon_Array_New(v8::Local<v8::Array> *out, v8::Isolate *isolate, uint32_t length) {
   *out = v8::Array::New(isolate, length);
 }
@@ -154,11 +435,244 @@ extern "C" uint32_t Neon_Array_Length(v8::Local<v8::Array> array) {
   return array->Length();
 }
 
+// Overloads used by the bulk conversions below to convert between elements and numbers.
+
+static v8::Local<v8::Value> Neon_Array_NewElement(v8::Isolate *isolate, double value) {
+  return v8::Number::New(isolate, value);
+}
+
+static v8::Local<v8::Value> Neon_Array_NewElement(v8::Isolate *isolate, int32_t value) {
+  return v8::Integer::New(isolate, value);
+}
+
+static v8::Local<v8::Value> Neon_Array_NewElement(v8::Isolate *isolate, uint32_t value) {
+  return v8::Integer::NewFromUnsigned(isolate, value);
+}
+
+static bool Neon_Array_ReadElement(double *out, v8::Local<v8::Value> value) {
+  return value->IsNumber() && (*out = value.As<v8::Number>()->Value(), true);
+}
+
+static bool Neon_Array_ReadElement(int32_t *out, v8::Local<v8::Value> value) {
+  return value->IsInt32() && (*out = value.As<v8::Int32>()->Value(), true);
+}
+
+static bool Neon_Array_ReadElement(uint32_t *out, v8::Local<v8::Value> value) {
+  return value->IsUint32() && (*out = value.As<v8::Uint32>()->Value(), true);
+}
+
+template <typename T>
+static bool Neon_Array_FromSlice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const T *data, uint32_t len) {
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Array> array = v8::Array::New(isolate, len);
+  for (uint32_t i = 0; i < len; i++) {
+    if (!array->Set(context, i, Neon_Array_NewElement(isolate, data[i])).FromMaybe(false)) {
+      return false;
+    }
+  }
+  *out = array;
+  return true;
+}
+
+// Reports an element of the wrong type through `index` and `element` without throwing, so the
+// caller can describe it in the same way as `JsArray::get_as`.
+template <typename T>
+static bool Neon_Array_ToSlice(T *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len) {
+  v8::Local<v8::Context> context = Nan::GetCurrentContext();
+  for (uint32_t i = 0; i < len; i++) {
+    v8::Local<v8::Value> value;
+    if (!array->Get(context, i).ToLocal(&value)) {
+      return false;
+    }
+    if (!Neon_Array_ReadElement(&out[i], value)) {
+      *index = i;
+      *element = value;
+      return false;
+    }
+  }
+  return true;
+}
+
+extern "C" bool Neon_Array_FromF64Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const double *data, uint32_t len) {
+  return Neon_Array_FromSlice(out, isolate, data, len);
+}
+
+extern "C" bool Neon_Array_FromI32Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const int32_t *data, uint32_t len) {
+  return Neon_Array_FromSlice(out, isolate, data, len);
+}
+
+extern "C" bool Neon_Array_FromU32Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const uint32_t *data, uint32_t len) {
+  return Neon_Array_FromSlice(out, isolate, data, len);
+}
+
+extern "C" bool Neon_Array_ToF64Slice(double *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len) {
+  return Neon_Array_ToSlice(out, index, element, array, len);
+}
+
+extern "C" bool Neon_Array_ToI32Slice(int32_t *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len) {
+  return Neon_Array_ToSlice(out, index, element, array, len);
+}
+
+extern "C" bool Neon_Array_ToU32Slice(uint32_t *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len) {
+  return Neon_Array_ToSlice(out, index, element, array, len);
+}
+
+extern "C" void Neon_Map_New(v8::Local<v8::Map> *out, v8::Isolate *isolate) {
+  *out = v8::Map::New(isolate);
+}
+
+extern "C" uint32_t Neon_Map_Size(v8::Local<v8::Map> map) {
+  return static_cast<uint32_t>(map->Size());
+}
+
+extern "C" bool Neon_Map_Get(v8::Local<v8::Value> *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key) {
+  Nan::MaybeLocal<v8::Value> maybe = map->Get(Nan::GetCurrentContext(), key);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_Map_Set(v8::Local<v8::Map> map, v8::Local<v8::Value> key, v8::Local<v8::Value> val) {
+  Nan::MaybeLocal<v8::Map> maybe = map->Set(Nan::GetCurrentContext(), key, val);
+  return !maybe.IsEmpty();
+}
+
+extern "C" bool Neon_Map_Has(bool *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key) {
+  Nan::Maybe<bool> maybe = map->Has(Nan::GetCurrentContext(), key);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Map_Delete(bool *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key) {
+  Nan::Maybe<bool> maybe = map->Delete(Nan::GetCurrentContext(), key);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" void Neon_Map_Clear(v8::Local<v8::Map> map) {
+  map->Clear();
+}
+
+extern "C" void Neon_Map_AsArray(v8::Local<v8::Array> *out, v8::Local<v8::Map> map) {
+  *out = map->AsArray();
+}
+
+extern "C" bool Neon_Promise_Resolve(v8::Local<v8::Promise> *out, v8::Local<v8::Value> value) {
+  v8::Local<v8::Context> context = Nan::GetCurrentContext();
+  if (value->IsPromise()) {
+    *out = value.As<v8::Promise>();
+    return true;
+  }
+  v8::Local<v8::Promise::Resolver> resolver;
+  if (!v8::Promise::Resolver::New(context).ToLocal(&resolver)) {
+    return false;
+  }
+  // Resolving with a thenable makes the promise follow the thenable's state.
+  if (resolver->Resolve(context, value).IsNothing()) {
+    return false;
+  }
+  *out = resolver->GetPromise();
+  return true;
+}
+
+extern "C" bool Neon_Promise_Then(v8::Local<v8::Value> *out, v8::Local<v8::Promise> promise, v8::Local<v8::Function> on_fulfilled, v8::Local<v8::Function> on_rejected) {
+#if V8_MAJOR_VERSION > 6 || (V8_MAJOR_VERSION == 6 && V8_MINOR_VERSION >= 5)
+  v8::Local<v8::Promise> result;
+  if (!promise->Then(Nan::GetCurrentContext(), on_fulfilled, on_rejected).ToLocal(&result)) {
+    return false;
+  }
+  *out = result;
+  return true;
+#else
+  // Older versions of V8 can only attach both callbacks through the promise's `then` method.
+  v8::Local<v8::Value> then;
+  if (!Nan::Get(promise, Nan::New("then").ToLocalChecked()).ToLocal(&then)) {
+    return false;
+  }
+  if (!then->IsFunction()) {
+    Nan::ThrowTypeError("promise.then is not a function");
+    return false;
+  }
+  v8::Local<v8::Value> argv[] = { on_fulfilled, on_rejected };
+  return Nan::Call(then.As<v8::Function>(), promise, 2, argv).ToLocal(out);
+#endif
+}
+
+extern "C" void Neon_Set_New(v8::Local<v8::Set> *out, v8::Isolate *isolate) {
+  *out = v8::Set::New(isolate);
+}
+
+extern "C" uint32_t Neon_Set_Size(v8::Local<v8::Set> set) {
+  return static_cast<uint32_t>(set->Size());
+}
+
+extern "C" bool Neon_Set_Add(v8::Local<v8::Set> set, v8::Local<v8::Value> val) {
+  Nan::MaybeLocal<v8::Set> maybe = set->Add(Nan::GetCurrentContext(), val);
+  return !maybe.IsEmpty();
+}
+
+extern "C" bool Neon_Set_Has(bool *out, v8::Local<v8::Set> set, v8::Local<v8::Value> val) {
+  Nan::Maybe<bool> maybe = set->Has(Nan::GetCurrentContext(), val);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Set_Delete(bool *out, v8::Local<v8::Set> set, v8::Local<v8::Value> val) {
+  Nan::Maybe<bool> maybe = set->Delete(Nan::GetCurrentContext(), val);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" void Neon_Set_Clear(v8::Local<v8::Set> set) {
+  set->Clear();
+}
+
+extern "C" void Neon_Set_AsArray(v8::Local<v8::Array> *out, v8::Local<v8::Set> set) {
+  *out = set->AsArray();
+}
+
 extern "C" bool Neon_String_New(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len) {
   Nan::MaybeLocal<v8::String> maybe = v8::String::NewFromUtf8(isolate, (const char*)data, v8::NewStringType::kNormal, len);
   return maybe.ToLocal(out);
 }
 
+extern "C" bool Neon_String_NewInternalized(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len) {
+  Nan::MaybeLocal<v8::String> maybe = v8::String::NewFromUtf8(isolate, (const char*)data, v8::NewStringType::kInternalized, len);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_String_NewFromUtf16(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint16_t *data, int32_t len) {
+  Nan::MaybeLocal<v8::String> maybe = v8::String::NewFromTwoByte(isolate, data, v8::NewStringType::kNormal, len);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_String_NewFromOneByte(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len) {
+  Nan::MaybeLocal<v8::String> maybe = v8::String::NewFromOneByte(isolate, data, v8::NewStringType::kNormal, len);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_String_NewExternal(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, size_t len, void *owner, Neon_DropCallback drop) {
+  // The resource takes ownership of the data immediately, so it gets dropped even if creating
+  // the string fails below.
+  neon::ExternalOneByteString *resource = new neon::ExternalOneByteString((const char *)data, len, owner, drop);
+  v8::MaybeLocal<v8::String> maybe = v8::String::NewExternalOneByte(isolate, resource);
+  if (maybe.IsEmpty()) {
+    delete resource;
+    return false;
+  }
+  return maybe.ToLocal(out);
+}
+
+extern "C" int32_t Neon_String_Length(v8::Local<v8::String> str) {
+  return str->Length();
+}
+
+extern "C" bool Neon_String_IsOneByte(v8::Local<v8::String> str) {
+  return str->ContainsOnlyOneByte();
+}
+
+extern "C" int32_t Neon_String_WriteUtf16(uint16_t *out, int32_t len, v8::Local<v8::String> str) {
+  return str->Write(out, 0, len, v8::String::NO_NULL_TERMINATION);
+}
+
+extern "C" int32_t Neon_String_WriteOneByte(uint8_t *out, int32_t len, v8::Local<v8::String> str) {
+  return str->WriteOneByte(out, 0, len, v8::String::NO_NULL_TERMINATION);
+}
+
 extern "C" int32_t Neon_String_Utf8Length(v8::Local<v8::String> str) {
   return str->Utf8Length();
 }
@@ -167,6 +681,19 @@ extern "C" size_t Neon_String_Data(char *out, size_t len, v8::Local<v8::Value> s
   return Nan::DecodeWrite(out, len, str, Nan::UTF8);
 }
 
+extern "C" bool Neon_RegExp_New(v8::Local<v8::RegExp> *out, v8::Local<v8::String> pattern, uint32_t flags) {
+  Nan::MaybeLocal<v8::RegExp> maybe = v8::RegExp::New(Nan::GetCurrentContext(), pattern, static_cast<v8::RegExp::Flags>(flags));
+  return maybe.ToLocal(out);
+}
+
+extern "C" void Neon_RegExp_Source(v8::Local<v8::String> *out, v8::Local<v8::RegExp> regexp) {
+  *out = regexp->GetSource();
+}
+
+extern "C" uint32_t Neon_RegExp_Flags(v8::Local<v8::RegExp> regexp) {
+  return static_cast<uint32_t>(regexp->GetFlags());
+}
+
 extern "C" bool Neon_Convert_ToString(v8::Local<v8::String> *out, v8::Local<v8::Value> value) {
   Nan::MaybeLocal<v8::String> maybe = Nan::To<v8::String>(value);
   return maybe.ToLocal(out);
@@ -177,6 +704,103 @@ extern "C" bool Neon_Convert_ToObject(v8::Local<v8::Object> *out, v8::Local<v8::
   return maybe.ToLocal(out);
 }
 
+extern "C" bool Neon_Convert_ToNumber(v8::Local<v8::Number> *out, v8::Local<v8::Value> value) {
+  Nan::MaybeLocal<v8::Number> maybe = Nan::To<v8::Number>(value);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_Convert_ToBoolean(v8::Local<v8::Boolean> *out, v8::Local<v8::Value> value) {
+  Nan::MaybeLocal<v8::Boolean> maybe = Nan::To<v8::Boolean>(value);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_Convert_ToInteger(v8::Local<v8::Integer> *out, v8::Local<v8::Value> value) {
+  Nan::MaybeLocal<v8::Integer> maybe = Nan::To<v8::Integer>(value);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_Convert_ToInt32(v8::Local<v8::Int32> *out, v8::Local<v8::Value> value) {
+  Nan::MaybeLocal<v8::Int32> maybe = Nan::To<v8::Int32>(value);
+  return maybe.ToLocal(out);
+}
+
+extern "C" bool Neon_Convert_ToUint32(v8::Local<v8::Uint32> *out, v8::Local<v8::Value> value) {
+  Nan::MaybeLocal<v8::Uint32> maybe = Nan::To<v8::Uint32>(value);
+  return maybe.ToLocal(out);
+}
+
+// V8 doesn't expose the ToPrimitive abstract operation, so this follows the steps of
+// the spec: call `obj[Symbol.toPrimitive](hint)` if it exists, and otherwise try the
+// `valueOf` and `toString` methods in the order determined by the hint.
+//
+// See: https://tc39.github.io/ecma262/#sec-toprimitive
+extern "C" bool Neon_Convert_ToPrimitive(v8::Local<v8::Value> *out, v8::Local<v8::Value> value, Neon_PrimitiveHint hint) {
+  if (!value->IsObject()) {
+    *out = value;
+    return true;
+  }
+
+  Nan::EscapableHandleScope scope;
+  v8::Isolate *isolate = v8::Isolate::GetCurrent();
+  v8::Local<v8::Object> obj = value.As<v8::Object>();
+
+  v8::Local<v8::Value> exotic;
+  if (!Nan::Get(obj, v8::Symbol::GetToPrimitive(isolate)).ToLocal(&exotic)) {
+    return false;
+  }
+
+  v8::Local<v8::Value> result;
+
+  if (!exotic->IsUndefined() && !exotic->IsNull()) {
+    if (!exotic->IsFunction()) {
+      Nan::ThrowTypeError("Symbol.toPrimitive is not a function");
+      return false;
+    }
+    const char *hint_name = hint == Neon_PrimitiveHint_Number ? "number"
+                          : hint == Neon_PrimitiveHint_String ? "string"
+                          : "default";
+    v8::Local<v8::Value> argv[] = { Nan::New(hint_name).ToLocalChecked() };
+    if (!Nan::Call(exotic.As<v8::Function>(), obj, 1, argv).ToLocal(&result)) {
+      return false;
+    }
+    if (result->IsObject()) {
+      Nan::ThrowTypeError("Cannot convert object to primitive value");
+      return false;
+    }
+    *out = scope.Escape(result);
+    return true;
+  }
+
+  const char *method_names[2];
+  if (hint == Neon_PrimitiveHint_String) {
+    method_names[0] = "toString";
+    method_names[1] = "valueOf";
+  } else {
+    method_names[0] = "valueOf";
+    method_names[1] = "toString";
+  }
+
+  for (int i = 0; i < 2; i++) {
+    v8::Local<v8::Value> method;
+    if (!Nan::Get(obj, Nan::New(method_names[i]).ToLocalChecked()).ToLocal(&method)) {
+      return false;
+    }
+    if (!method->IsFunction()) {
+      continue;
+    }
+    if (!Nan::Call(method.As<v8::Function>(), obj, 0, nullptr).ToLocal(&result)) {
+      return false;
+    }
+    if (!result->IsObject()) {
+      *out = scope.Escape(result);
+      return true;
+    }
+  }
+
+  Nan::ThrowTypeError("Cannot convert object to primitive value");
+  return false;
+}
+
 extern "C" bool Neon_Buffer_New(v8::Local<v8::Object> *out, uint32_t size) {
   Nan::MaybeLocal<v8::Object> maybe = Nan::NewBuffer(size);
   return maybe.ToLocal(out);
@@ -391,14 +1015,59 @@ extern "C" void *Neon_Class_GetInstanceInternals(v8::Local<v8::Object> obj) {
   return static_cast<neon::BaseClassInstanceMetadata *>(obj->GetAlignedPointerFromInternalField(0))->GetInternals();
 }
 
-extern "C" bool Neon_Fun_Template_New(v8::Local<v8::FunctionTemplate> *out, v8::Isolate *isolate, callback_t callback) {
+// Boxes are plain objects carrying a private (i.e., invisible to JavaScript) property
+// that refers to the metadata owning the Rust data. The private key is looked up by
+// name, so every box created in an isolate shares the same key.
+static v8::Local<v8::Private> Neon_Box_Key(v8::Isolate *isolate) {
+  return v8::Private::ForApi(isolate, Nan::New("neon::box").ToLocalChecked());
+}
+
+extern "C" bool Neon_Box_New(v8::Local<v8::Object> *out, v8::Isolate *isolate, void *data, Neon_DropCallback drop) {
+  v8::Local<v8::Object> obj = Nan::New<v8::Object>();
+  // The metadata takes ownership of the data immediately, so it gets dropped when the
+  // object is collected even if attaching it below fails.
+  neon::BaseClassInstanceMetadata *metadata = new neon::BaseClassInstanceMetadata(isolate, obj, data, drop);
+  v8::Local<v8::External> wrapper = v8::External::New(isolate, metadata);
+  Nan::Maybe<bool> maybe = obj->SetPrivate(isolate->GetCurrentContext(), Neon_Box_Key(isolate), wrapper);
+  if (!maybe.IsJust() || !maybe.FromJust()) {
+    return false;
+  }
+  *out = obj;
+  return true;
+}
+
+extern "C" void *Neon_Box_Get(v8::Local<v8::Object> obj) {
+  v8::Isolate *isolate = obj->GetIsolate();
+  v8::Local<v8::Value> wrapper = obj->GetPrivate(isolate->GetCurrentContext(), Neon_Box_Key(isolate)).ToLocalChecked();
+  neon::BaseClassInstanceMetadata *metadata = static_cast<neon::BaseClassInstanceMetadata *>(wrapper.As<v8::External>()->Value());
+  return metadata->GetInternals();
+}
+
+// The data value of a Neon function is an array pairing its payload, such as the dynamic
+// callback, with the source of its name for argument errors: a string, or the function itself.
+// This lets the callback name the function without looking up the callee.
+static bool Neon_Fun_NewData(v8::Local<v8::Array> *out, v8::Isolate *isolate, v8::Local<v8::Value> payload, v8::Local<v8::Value> name) {
+  v8::Local<v8::Array> data = v8::Array::New(isolate, 2);
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  if (data->Set(context, 0, payload).IsNothing() || data->Set(context, 1, name).IsNothing()) {
+    return false;
+  }
+  *out = data;
+  return true;
+}
+
+extern "C" bool Neon_Fun_Template_New(v8::Local<v8::FunctionTemplate> *out, v8::Isolate *isolate, callback_t callback, v8::Local<v8::String> name) {
   v8::Local<v8::External> wrapper = v8::External::New(isolate, callback.dynamic_callback);
   if (wrapper.IsEmpty()) {
     return false;
   }
+  v8::Local<v8::Array> data;
+  if (!Neon_Fun_NewData(&data, isolate, wrapper, name)) {
+    return false;
+  }
 
   v8::FunctionCallback static_callback = reinterpret_cast<v8::FunctionCallback>(callback.static_callback);
-  v8::MaybeLocal<v8::FunctionTemplate> maybe_result = v8::FunctionTemplate::New(isolate, static_callback, wrapper);
+  v8::MaybeLocal<v8::FunctionTemplate> maybe_result = v8::FunctionTemplate::New(isolate, static_callback, data);
   return maybe_result.ToLocal(out);
 }
 
@@ -407,14 +1076,33 @@ extern "C" bool Neon_Fun_New(v8::Local<v8::Function> *out, v8::Isolate *isolate,
   if (wrapper.IsEmpty()) {
     return false;
   }
+  v8::Local<v8::Array> data;
+  if (!Neon_Fun_NewData(&data, isolate, wrapper, Nan::Undefined())) {
+    return false;
+  }
 
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
   v8::FunctionCallback static_callback = reinterpret_cast<v8::FunctionCallback>(callback.static_callback);
-  v8::MaybeLocal<v8::Function> maybe_result = v8::Function::New(isolate->GetCurrentContext(), static_callback, wrapper);
+  v8::MaybeLocal<v8::Function> maybe_result = v8::Function::New(context, static_callback, data);
+  // The function is named after it's created, so its data refers to the function itself.
+  return maybe_result.ToLocal(out) && data->Set(context, 1, *out).IsJust();
+}
+
+extern "C" bool Neon_Fun_NewWithData(v8::Local<v8::Function> *out, v8::Isolate *isolate, void *callback, v8::Local<v8::Value> data) {
+  v8::FunctionCallback static_callback = reinterpret_cast<v8::FunctionCallback>(callback);
+  v8::MaybeLocal<v8::Function> maybe_result = v8::Function::New(isolate->GetCurrentContext(), static_callback, data);
   return maybe_result.ToLocal(out);
 }
 
-extern "C" void *Neon_Fun_GetDynamicCallback(v8::Local<v8::External> data) {
-  return data->Value();
+extern "C" void *Neon_Fun_GetDynamicCallback(v8::Local<v8::Value> data) {
+  if (data->IsArray()) {
+    data = Nan::Get(data.As<v8::Array>(), 0).ToLocalChecked();
+  }
+  return data.As<v8::External>()->Value();
+}
+
+extern "C" void Neon_Fun_SetName(v8::Local<v8::Function> fun, v8::Local<v8::String> name) {
+  fun->SetName(name);
 }
 
 extern "C" bool Neon_Fun_Call(v8::Local<v8::Value> *out, v8::Isolate *isolate, v8::Local<v8::Function> fun, v8::Local<v8::Value> self, int32_t argc, v8::Local<v8::Value> argv[]) {
@@ -427,6 +1115,34 @@ extern "C" bool Neon_Fun_Construct(v8::Local<v8::Object> *out, v8::Isolate *isol
   return maybe_result.ToLocal(out);
 }
 
+extern "C" Neon_Tag Neon_Tag_Of(v8::Local<v8::Value> val) {
+  if (val->IsUndefined()) {
+    return Neon_Tag_Undefined;
+  } else if (val->IsNull()) {
+    return Neon_Tag_Null;
+  } else if (val->IsBoolean()) {
+    return Neon_Tag_Boolean;
+  } else if (val->IsNumber()) {
+    return Neon_Tag_Number;
+  } else if (val->IsString()) {
+    return Neon_Tag_String;
+  } else if (!val->IsObject()) {
+    return Neon_Tag_Other;
+  } else if (val->IsArray()) {
+    return Neon_Tag_Array;
+  } else if (val->IsFunction()) {
+    return Neon_Tag_Function;
+  } else if (node::Buffer::HasInstance(val)) {
+    return Neon_Tag_Buffer;
+  } else if (val->IsArrayBuffer()) {
+    return Neon_Tag_ArrayBuffer;
+  } else if (val->IsNativeError()) {
+    return Neon_Tag_Error;
+  } else {
+    return Neon_Tag_Object;
+  }
+}
+
 extern "C" bool Neon_Tag_IsUndefined(v8::Local<v8::Value> val) {
   return val->IsUndefined();
 }
@@ -455,18 +1171,52 @@ extern "C" bool Neon_Tag_IsArray(v8::Local<v8::Value> val) {
   return val->IsArray();
 }
 
+extern "C" bool Neon_Tag_IsMap(v8::Local<v8::Value> val) {
+  return val->IsMap();
+}
+
+extern "C" bool Neon_Tag_IsSet(v8::Local<v8::Value> val) {
+  return val->IsSet();
+}
+
+extern "C" bool Neon_Tag_IsPromise(v8::Local<v8::Value> val) {
+  return val->IsPromise();
+}
+
 extern "C" bool Neon_Tag_IsFunction(v8::Local<v8::Value> val) {
   return val->IsFunction();
 }
 
+extern "C" bool Neon_Tag_IsRegExp(v8::Local<v8::Value> val) {
+  return val->IsRegExp();
+}
+
 extern "C" bool Neon_Tag_IsError(v8::Local<v8::Value> val) {
   return val->IsNativeError();
 }
 
+extern "C" bool Neon_Tag_IsBox(v8::Local<v8::Value> val) {
+  if (!val->IsObject()) {
+    return false;
+  }
+  v8::Isolate *isolate = v8::Isolate::GetCurrent();
+  Nan::Maybe<bool> maybe = val.As<v8::Object>()->HasPrivate(isolate->GetCurrentContext(), Neon_Box_Key(isolate));
+  return maybe.IsJust() && maybe.FromJust();
+}
+
 extern "C" void Neon_Error_Throw(v8::Local<v8::Value> val) {
   Nan::ThrowError(val);
 }
 
+extern "C" bool Neon_Error_TryCatch(v8::Local<v8::Value> *out, bool (*callback)(void *), void *data) {
+  Nan::TryCatch try_catch;
+  if (callback(data) || !try_catch.HasCaught()) {
+    return false;
+  }
+  *out = try_catch.Exception();
+  return true;
+}
+
 extern "C" void Neon_Error_NewError(v8::Local<v8::Value> *out, v8::Local<v8::String> msg) {
   *out = v8::Exception::Error(msg);
 }
@@ -511,8 +1261,254 @@ extern "C" bool Neon_Mem_SameHandle(v8::Local<v8::Value> v1, v8::Local<v8::Value
   return v1 == v2;
 }
 
+extern "C" void *Neon_Mem_NewPersistent(v8::Local<v8::Value> h) {
+  return new Nan::Persistent<v8::Value>(h);
+}
+
+extern "C" void Neon_Mem_ReadPersistent(v8::Local<v8::Value> *out, void *persistent) {
+  *out = Nan::New(*static_cast<Nan::Persistent<v8::Value> *>(persistent));
+}
+
+extern "C" void Neon_Mem_DropPersistent(void *persistent) {
+  // Nan::Persistent resets the handle when it's destroyed.
+  delete static_cast<Nan::Persistent<v8::Value> *>(persistent);
+}
+
+extern "C" bool Neon_Value_StrictEquals(v8::Local<v8::Value> v1, v8::Local<v8::Value> v2) {
+  return v1->StrictEquals(v2);
+}
+
+extern "C" bool Neon_Value_LooseEquals(bool *out, v8::Local<v8::Value> v1, v8::Local<v8::Value> v2) {
+  Nan::Maybe<bool> maybe = v1->Equals(Nan::GetCurrentContext(), v2);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" bool Neon_Value_SameValue(v8::Local<v8::Value> v1, v8::Local<v8::Value> v2) {
+  return v1->SameValue(v2);
+}
+
+extern "C" Neon_TypeOf Neon_Value_TypeOf(v8::Local<v8::Value> val) {
+  if (val->IsUndefined()) {
+    return Neon_TypeOf_Undefined;
+  } else if (val->IsBoolean()) {
+    return Neon_TypeOf_Boolean;
+  } else if (val->IsNumber()) {
+    return Neon_TypeOf_Number;
+  } else if (val->IsString()) {
+    return Neon_TypeOf_String;
+  } else if (val->IsSymbol()) {
+    return Neon_TypeOf_Symbol;
+  } else if (val->IsFunction()) {
+    return Neon_TypeOf_Function;
+  }
+#if V8_MAJOR_VERSION > 6 || (V8_MAJOR_VERSION == 6 && V8_MINOR_VERSION >= 7)
+  if (val->IsBigInt()) {
+    return Neon_TypeOf_BigInt;
+  }
+#endif
+  return Neon_TypeOf_Object;
+}
+
+extern "C" bool Neon_Value_InstanceOf(bool *out, v8::Local<v8::Value> val, v8::Local<v8::Object> ctor) {
+  Nan::Maybe<bool> maybe = val->InstanceOf(Nan::GetCurrentContext(), ctor);
+  return maybe.IsJust() && (*out = maybe.FromJust(), true);
+}
+
+extern "C" void Neon_Iterator_Symbol(v8::Local<v8::Symbol> *out, v8::Isolate *isolate) {
+  *out = v8::Symbol::GetIterator(isolate);
+}
+
+extern "C" bool Neon_Iterator_Get(v8::Local<v8::Object> *iterator, v8::Local<v8::Function> *next, v8::Local<v8::Value> iterable) {
+  v8::Isolate *isolate = v8::Isolate::GetCurrent();
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  v8::Local<v8::Object> obj;
+  if (!iterable->ToObject(context).ToLocal(&obj)) {
+    return false;
+  }
+  v8::Local<v8::Value> method;
+  if (!obj->Get(context, v8::Symbol::GetIterator(isolate)).ToLocal(&method)) {
+    return false;
+  }
+  if (!method->IsFunction()) {
+    Nan::ThrowTypeError("value is not iterable");
+    return false;
+  }
+  v8::Local<v8::Value> result;
+  if (!method.As<v8::Function>()->Call(context, iterable, 0, nullptr).ToLocal(&result)) {
+    return false;
+  }
+  if (!result->IsObject()) {
+    Nan::ThrowTypeError("Result of the Symbol.iterator method is not an object");
+    return false;
+  }
+  v8::Local<v8::Value> next_method;
+  if (!result.As<v8::Object>()->Get(context, Nan::New("next").ToLocalChecked()).ToLocal(&next_method)) {
+    return false;
+  }
+  if (!next_method->IsFunction()) {
+    Nan::ThrowTypeError("iterator.next is not a function");
+    return false;
+  }
+  *iterator = result.As<v8::Object>();
+  *next = next_method.As<v8::Function>();
+  return true;
+}
+
+extern "C" bool Neon_Iterator_Next(v8::Local<v8::Value> *out_value, bool *out_done, v8::Local<v8::Object> iterator, v8::Local<v8::Function> next) {
+  v8::Local<v8::Context> context = Nan::GetCurrentContext();
+  v8::Local<v8::Value> result;
+  if (!next->Call(context, iterator, 0, nullptr).ToLocal(&result)) {
+    return false;
+  }
+  if (!result->IsObject()) {
+    Nan::ThrowTypeError("Iterator result is not an object");
+    return false;
+  }
+  v8::Local<v8::Object> obj = result.As<v8::Object>();
+  v8::Local<v8::Value> done;
+  if (!obj->Get(context, Nan::New("done").ToLocalChecked()).ToLocal(&done)) {
+    return false;
+  }
+  *out_done = Nan::To<bool>(done).FromJust();
+  if (*out_done) {
+    return true;
+  }
+  return obj->Get(context, Nan::New("value").ToLocalChecked()).ToLocal(out_value);
+}
+
+extern "C" bool Neon_Iterator_Close(v8::Local<v8::Object> iterator) {
+  v8::Local<v8::Context> context = Nan::GetCurrentContext();
+  v8::Local<v8::Value> method;
+  if (!iterator->Get(context, Nan::New("return").ToLocalChecked()).ToLocal(&method)) {
+    return false;
+  }
+  if (method->IsUndefined() || method->IsNull()) {
+    return true;
+  }
+  if (!method->IsFunction()) {
+    Nan::ThrowTypeError("iterator.return is not a function");
+    return false;
+  }
+  v8::Local<v8::Value> result;
+  if (!method.As<v8::Function>()->Call(context, iterator, 0, nullptr).ToLocal(&result)) {
+    return false;
+  }
+  if (!result->IsObject()) {
+    Nan::ThrowTypeError("Iterator result is not an object");
+    return false;
+  }
+  return true;
+}
+
+extern "C" void Neon_Iterator_CloseAfterThrow(v8::Local<v8::Object> iterator) {
+  Nan::TryCatch try_catch;
+  Neon_Iterator_Close(iterator);
+}
+
+extern "C" bool Neon_JSON_Parse(v8::Local<v8::Value> *out, v8::Local<v8::String> text) {
+  Nan::JSON json;
+  return json.Parse(text).ToLocal(out);
+}
+
+extern "C" bool Neon_JSON_Stringify(v8::Local<v8::String> *out, v8::Local<v8::Value> value, v8::Local<v8::String> gap) {
+  // JSON.stringify produces undefined rather than a string for these values.
+  if (value->IsUndefined() || value->IsFunction() || value->IsSymbol()) {
+    Nan::ThrowTypeError("value cannot be converted to JSON");
+    return false;
+  }
+  Nan::JSON json;
+  // Nan::JSON only accepts objects in its signature, but passes the value straight through to
+  // the engine's JSON.stringify, which accepts any value.
+  v8::Local<v8::Value> result;
+  if (!json.Stringify(value.As<v8::Object>(), gap).ToLocal(&result)) {
+    return false;
+  }
+  if (!result->IsString()) {
+    Nan::ThrowTypeError("value cannot be converted to JSON");
+    return false;
+  }
+  *out = result.As<v8::String>();
+  return true;
+}
+
+extern "C" bool Neon_Serialize_Write(uint8_t **out_data, size_t *out_len, v8::Isolate *isolate, v8::Local<v8::Value> value, void *host, Neon_WriteHostCallback write_host) {
+#ifdef NEON_HAS_VALUE_SERIALIZER
+  neon::SerializerDelegate delegate(isolate, host, write_host);
+  v8::ValueSerializer serializer(isolate, &delegate);
+  delegate.SetSerializer(&serializer);
+  serializer.WriteHeader();
+  v8::Maybe<bool> maybe = serializer.WriteValue(isolate->GetCurrentContext(), value);
+  if (maybe.IsNothing()) {
+    return false;
+  }
+  std::pair<uint8_t *, size_t> buffer = serializer.Release();
+  *out_data = buffer.first;
+  *out_len = buffer.second;
+  return true;
+#else
+  Nan::ThrowError("serialization requires Node 8 or later");
+  return false;
+#endif
+}
+
+extern "C" void Neon_Serialize_FreeBuffer(uint8_t *data) {
+  // The serializer allocates with realloc unless its delegate overrides that.
+  free(data);
+}
+
+extern "C" bool Neon_Serialize_Read(v8::Local<v8::Value> *out, v8::Isolate *isolate, const uint8_t *data, size_t len, void *host, Neon_ReadHostCallback read_host) {
+#ifdef NEON_HAS_VALUE_SERIALIZER
+  Nan::EscapableHandleScope scope;
+  v8::Local<v8::Context> context = isolate->GetCurrentContext();
+  neon::DeserializerDelegate delegate(host, read_host);
+  v8::ValueDeserializer deserializer(isolate, data, len, &delegate);
+  delegate.SetDeserializer(&deserializer);
+  v8::Maybe<bool> header = deserializer.ReadHeader(context);
+  if (header.IsNothing()) {
+    return false;
+  }
+  v8::Local<v8::Value> result;
+  if (!deserializer.ReadValue(context).ToLocal(&result)) {
+    return false;
+  }
+  *out = scope.Escape(result);
+  return true;
+#else
+  Nan::ThrowError("serialization requires Node 8 or later");
+  return false;
+#endif
+}
+
 extern "C" void Neon_Task_Schedule(void *task, Neon_TaskPerformCallback perform, Neon_TaskCompleteCallback complete, v8::Local<v8::Function> callback) {
   v8::Isolate *isolate = v8::Isolate::GetCurrent();
   neon::Task *internal_task = new neon::Task(isolate, task, perform, complete, callback);
   neon::queue_task(internal_task);
 }
+
+extern "C" void *Neon_Task_ScheduleCancellable(void *task, Neon_TaskPerformCallback perform, Neon_TaskCompleteCallback complete, v8::Local<v8::Function> callback) {
+  v8::Isolate *isolate = v8::Isolate::GetCurrent();
+  neon::Task *internal_task = new neon::Task(isolate, task, perform, complete, callback);
+  neon::queue_task(internal_task);
+  return internal_task;
+}
+
+extern "C" bool Neon_Task_Cancel(void *request) {
+  return static_cast<neon::Task*>(request)->cancel();
+}
+
+extern "C" bool Neon_Future_New(v8::Local<v8::Promise> *out, void **out_handle, void *future, Neon_FutureCompleteCallback complete) {
+  v8::Isolate *isolate = v8::Isolate::GetCurrent();
+  v8::Local<v8::Promise::Resolver> resolver;
+  if (!v8::Promise::Resolver::New(isolate->GetCurrentContext()).ToLocal(&resolver)) {
+    return false;
+  }
+  neon::Future *internal_future = new neon::Future(isolate, future, complete, resolver);
+  neon::start_future(internal_future);
+  *out = resolver->GetPromise();
+  *out_handle = internal_future;
+  return true;
+}
+
+extern "C" void Neon_Future_Send(void *handle) {
+  static_cast<neon::Future*>(handle)->send();
+}
@@ -11,18 +11,58 @@ typedef struct {
   void* dynamic_callback;
 } callback_t;
 
+// corresponding Rust enum `TypeOf` defined in value.rs
+typedef enum {
+  Neon_TypeOf_Undefined,
+  Neon_TypeOf_Object,
+  Neon_TypeOf_Boolean,
+  Neon_TypeOf_Number,
+  Neon_TypeOf_String,
+  Neon_TypeOf_Symbol,
+  Neon_TypeOf_Function,
+  Neon_TypeOf_BigInt
+} Neon_TypeOf;
+
+// corresponding Rust enum `PrimitiveHint` defined in convert.rs
+typedef enum {
+  Neon_PrimitiveHint_Default,
+  Neon_PrimitiveHint_Number,
+  Neon_PrimitiveHint_String
+} Neon_PrimitiveHint;
+
+// corresponding Rust enum `Tag` defined in tag.rs
+typedef enum {
+  Neon_Tag_Undefined,
+  Neon_Tag_Null,
+  Neon_Tag_Boolean,
+  Neon_Tag_Number,
+  Neon_Tag_String,
+  Neon_Tag_Array,
+  Neon_Tag_Function,
+  Neon_Tag_Buffer,
+  Neon_Tag_ArrayBuffer,
+  Neon_Tag_Error,
+  Neon_Tag_Object,
+  Neon_Tag_Other
+} Neon_Tag;
+
 extern "C" {
 
+  typedef void (*Neon_DropCallback)(void *);
+
   void Neon_Call_SetReturn(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::Value> value);
   void *Neon_Call_GetIsolate(v8::FunctionCallbackInfo<v8::Value> *info);
   void *Neon_Call_CurrentIsolate();
   bool Neon_Call_IsConstruct(v8::FunctionCallbackInfo<v8::Value> *info);
+  bool Neon_Call_CalleeName(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::String> *out);
   void Neon_Call_This(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::Object> *out);
   void Neon_Call_Data(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::Value> *out);
   int32_t Neon_Call_Length(v8::FunctionCallbackInfo<v8::Value> *info);
   void Neon_Call_Get(v8::FunctionCallbackInfo<v8::Value> *info, int32_t i, v8::Local<v8::Value> *out);
 
   void Neon_Primitive_Number(v8::Local<v8::Number> *out, v8::Isolate *isolate, double value);
+  void Neon_Primitive_Integer(v8::Local<v8::Integer> *out, v8::Isolate *isolate, int32_t value);
+  void Neon_Primitive_Uint32(v8::Local<v8::Integer> *out, v8::Isolate *isolate, uint32_t value);
   void Neon_Primitive_Undefined(v8::Local<v8::Primitive> *out);
   void Neon_Primitive_Null(v8::Local<v8::Primitive> *out);
   void Neon_Primitive_Boolean(v8::Local<v8::Boolean> *out, bool b);
@@ -31,6 +71,7 @@ extern "C" {
 
   void Neon_Object_New(v8::Local<v8::Object> *out);
   bool Neon_Object_GetOwnPropertyNames(v8::Local<v8::Array> *out, v8::Local<v8::Object> obj);
+  bool Neon_Object_GetPropertyNames(v8::Local<v8::Array> *out, v8::Local<v8::Object> obj, bool own_only, bool enumerable_only, bool configurable_only, bool include_symbols, bool include_indices);
   void *Neon_Object_GetIsolate(v8::Local<v8::Object> obj);
   bool Neon_Object_Get_Index(v8::Local<v8::Value> *out, v8::Local<v8::Object> object, uint32_t index);
   bool Neon_Object_Set_Index(bool *out, v8::Local<v8::Object> object, uint32_t index, v8::Local<v8::Value> val);
@@ -38,16 +79,75 @@ extern "C" {
   bool Neon_Object_Set_String(bool *out, v8::Local<v8::Object> object, const uint8_t *key, int32_t len, v8::Local<v8::Value> val);
   bool Neon_Object_Get(v8::Local<v8::Value> *out, v8::Local<v8::Object> object, v8::Local<v8::Value> key);
   bool Neon_Object_Set(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key, v8::Local<v8::Value> val);
+  bool Neon_Object_Has(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key);
+  bool Neon_Object_HasOwn(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key);
+  bool Neon_Object_Delete(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key);
+  bool Neon_Object_DefineDataProperty(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key, v8::Local<v8::Value> val, bool writable, bool enumerable, bool configurable);
+  bool Neon_Object_DefineAccessorProperty(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key, v8::Local<v8::Value> getter, v8::Local<v8::Value> setter, bool enumerable, bool configurable);
+  bool Neon_Object_GetOwnPropertyDescriptor(v8::Local<v8::Value> *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> key);
+  bool Neon_Object_Create(v8::Local<v8::Object> *out, v8::Local<v8::Value> proto);
+  void Neon_Object_GetPrototype(v8::Local<v8::Value> *out, v8::Local<v8::Object> obj);
+  bool Neon_Object_SetPrototype(bool *out, v8::Local<v8::Object> obj, v8::Local<v8::Value> proto);
+  bool Neon_Object_Freeze(bool *out, v8::Local<v8::Object> obj);
+  bool Neon_Object_Seal(bool *out, v8::Local<v8::Object> obj);
+  bool Neon_Object_CaptureBuiltins(v8::Isolate *isolate);
+  bool Neon_Object_GetBuiltin(v8::Local<v8::Function> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len);
+  bool Neon_Object_FromEntries(v8::Local<v8::Object> *out, v8::Isolate *isolate, const uint8_t **keys, const int32_t *key_lens, const v8::Local<v8::Value> *values, uint32_t len);
+  bool Neon_Object_FromKeys(v8::Local<v8::Object> *out, v8::Isolate *isolate, v8::Local<v8::Array> keys, const v8::Local<v8::Value> *values, uint32_t len);
 
   void Neon_Array_New(v8::Local<v8::Array> *out, v8::Isolate *isolate, uint32_t length);
   uint32_t Neon_Array_Length(v8::Local<v8::Array> array);
+  bool Neon_Array_FromF64Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const double *data, uint32_t len);
+  bool Neon_Array_FromI32Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const int32_t *data, uint32_t len);
+  bool Neon_Array_FromU32Slice(v8::Local<v8::Array> *out, v8::Isolate *isolate, const uint32_t *data, uint32_t len);
+  bool Neon_Array_ToF64Slice(double *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len);
+  bool Neon_Array_ToI32Slice(int32_t *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len);
+  bool Neon_Array_ToU32Slice(uint32_t *out, uint32_t *index, v8::Local<v8::Value> *element, v8::Local<v8::Array> array, uint32_t len);
+
+  void Neon_Map_New(v8::Local<v8::Map> *out, v8::Isolate *isolate);
+  uint32_t Neon_Map_Size(v8::Local<v8::Map> map);
+  bool Neon_Map_Get(v8::Local<v8::Value> *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key);
+  bool Neon_Map_Set(v8::Local<v8::Map> map, v8::Local<v8::Value> key, v8::Local<v8::Value> val);
+  bool Neon_Map_Has(bool *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key);
+  bool Neon_Map_Delete(bool *out, v8::Local<v8::Map> map, v8::Local<v8::Value> key);
+  void Neon_Map_Clear(v8::Local<v8::Map> map);
+  void Neon_Map_AsArray(v8::Local<v8::Array> *out, v8::Local<v8::Map> map);
+
+  bool Neon_Promise_Resolve(v8::Local<v8::Promise> *out, v8::Local<v8::Value> value);
+  bool Neon_Promise_Then(v8::Local<v8::Value> *out, v8::Local<v8::Promise> promise, v8::Local<v8::Function> on_fulfilled, v8::Local<v8::Function> on_rejected);
+
+  void Neon_Set_New(v8::Local<v8::Set> *out, v8::Isolate *isolate);
+  uint32_t Neon_Set_Size(v8::Local<v8::Set> set);
+  bool Neon_Set_Add(v8::Local<v8::Set> set, v8::Local<v8::Value> val);
+  bool Neon_Set_Has(bool *out, v8::Local<v8::Set> set, v8::Local<v8::Value> val);
+  bool Neon_Set_Delete(bool *out, v8::Local<v8::Set> set, v8::Local<v8::Value> val);
+  void Neon_Set_Clear(v8::Local<v8::Set> set);
+  void Neon_Set_AsArray(v8::Local<v8::Array> *out, v8::Local<v8::Set> set);
 
   bool Neon_String_New(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len);
+  bool Neon_String_NewInternalized(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len);
+  bool Neon_String_NewFromUtf16(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint16_t *data, int32_t len);
+  bool Neon_String_NewFromOneByte(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, int32_t len);
+  bool Neon_String_NewExternal(v8::Local<v8::String> *out, v8::Isolate *isolate, const uint8_t *data, size_t len, void *owner, Neon_DropCallback drop);
+  int32_t Neon_String_Length(v8::Local<v8::String> str);
+  bool Neon_String_IsOneByte(v8::Local<v8::String> str);
+  int32_t Neon_String_WriteUtf16(uint16_t *out, int32_t len, v8::Local<v8::String> str);
+  int32_t Neon_String_WriteOneByte(uint8_t *out, int32_t len, v8::Local<v8::String> str);
   int32_t Neon_String_Utf8Length(v8::Local<v8::String> str);
   size_t Neon_String_Data(char *out, size_t len, v8::Local<v8::Value> str);
 
+  bool Neon_RegExp_New(v8::Local<v8::RegExp> *out, v8::Local<v8::String> pattern, uint32_t flags);
+  void Neon_RegExp_Source(v8::Local<v8::String> *out, v8::Local<v8::RegExp> regexp);
+  uint32_t Neon_RegExp_Flags(v8::Local<v8::RegExp> regexp);
+
   bool Neon_Convert_ToString(v8::Local<v8::String> *out, v8::Local<v8::Value> value);
   bool Neon_Convert_ToObject(v8::Local<v8::Object> *out, v8::Local<v8::Value> *value);
+  bool Neon_Convert_ToNumber(v8::Local<v8::Number> *out, v8::Local<v8::Value> value);
+  bool Neon_Convert_ToBoolean(v8::Local<v8::Boolean> *out, v8::Local<v8::Value> value);
+  bool Neon_Convert_ToInteger(v8::Local<v8::Integer> *out, v8::Local<v8::Value> value);
+  bool Neon_Convert_ToInt32(v8::Local<v8::Int32> *out, v8::Local<v8::Value> value);
+  bool Neon_Convert_ToUint32(v8::Local<v8::Uint32> *out, v8::Local<v8::Value> value);
+  bool Neon_Convert_ToPrimitive(v8::Local<v8::Value> *out, v8::Local<v8::Value> value, Neon_PrimitiveHint hint);
 
   bool Neon_Buffer_New(v8::Local<v8::Object> *out, uint32_t size);
   void Neon_Buffer_Data(void **base_out, size_t *len_out, v8::Local<v8::Object> obj);
@@ -73,8 +173,10 @@ extern "C" {
   void Neon_Scope_GetGlobal(v8::Isolate *isolate, v8::Local<v8::Value> *out);
 
   bool Neon_Fun_New(v8::Local<v8::Function> *out, v8::Isolate *isolate, callback_t callback);
-  bool Neon_Fun_Template_New(v8::Local<v8::FunctionTemplate> *out, v8::Isolate *isolate, callback_t callback);
-  void *Neon_Fun_GetDynamicCallback(v8::Local<v8::External> obj);
+  bool Neon_Fun_NewWithData(v8::Local<v8::Function> *out, v8::Isolate *isolate, void *callback, v8::Local<v8::Value> data);
+  bool Neon_Fun_Template_New(v8::Local<v8::FunctionTemplate> *out, v8::Isolate *isolate, callback_t callback, v8::Local<v8::String> name);
+  void *Neon_Fun_GetDynamicCallback(v8::Local<v8::Value> data);
+  void Neon_Fun_SetName(v8::Local<v8::Function> fun, v8::Local<v8::String> name);
   bool Neon_Fun_Call(v8::Local<v8::Value> *out, v8::Isolate *isolate, v8::Local<v8::Function> fun, v8::Local<v8::Value> self, int32_t argc, v8::Local<v8::Value> argv[]);
   bool Neon_Fun_Construct(v8::Local<v8::Object> *out, v8::Isolate *isolate, v8::Local<v8::Function> fun, int32_t argc, v8::Local<v8::Value> argv[]);
 
@@ -84,8 +186,6 @@ extern "C" {
   void Neon_Class_ForConstructor(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::FunctionTemplate> *out);
   void Neon_Class_ForMethod(v8::FunctionCallbackInfo<v8::Value> *info, v8::Local<v8::FunctionTemplate> *out);
 
-  typedef void (*Neon_DropCallback)(void *);
-
   void *Neon_Class_GetClassMap(v8::Isolate *isolate);
   void Neon_Class_SetClassMap(v8::Isolate *isolate, void *map, Neon_DropCallback free_map);
   void *Neon_Class_CreateBase(v8::Isolate *isolate,
@@ -106,8 +206,12 @@ extern "C" {
   bool Neon_Class_MetadataToConstructor(v8::Local<v8::Function> *out, v8::Isolate *isolate, void *metadata);
   void *Neon_Class_GetInstanceInternals(v8::Local<v8::Object> obj);
 
+  bool Neon_Box_New(v8::Local<v8::Object> *out, v8::Isolate *isolate, void *data, Neon_DropCallback drop);
+  void *Neon_Box_Get(v8::Local<v8::Object> obj);
+
   uint32_t Neon_Module_GetVersion();
 
+  Neon_Tag Neon_Tag_Of(v8::Local<v8::Value> val);
   bool Neon_Tag_IsUndefined(v8::Local<v8::Value> val);
   bool Neon_Tag_IsNull(v8::Local<v8::Value> val);
   bool Neon_Tag_IsBoolean(v8::Local<v8::Value> val);
@@ -115,10 +219,15 @@ extern "C" {
   bool Neon_Tag_IsString(v8::Local<v8::Value> val);
   bool Neon_Tag_IsObject(v8::Local<v8::Value> val);
   bool Neon_Tag_IsArray(v8::Local<v8::Value> val);
+  bool Neon_Tag_IsMap(v8::Local<v8::Value> val);
+  bool Neon_Tag_IsSet(v8::Local<v8::Value> val);
+  bool Neon_Tag_IsPromise(v8::Local<v8::Value> val);
   bool Neon_Tag_IsFunction(v8::Local<v8::Value> val);
   bool Neon_Tag_IsBuffer(v8::Local<v8::Value> obj);
   bool Neon_Tag_IsArrayBuffer(v8::Local<v8::Value> obj);
+  bool Neon_Tag_IsRegExp(v8::Local<v8::Value> val);
   bool Neon_Tag_IsError(v8::Local<v8::Value> val);
+  bool Neon_Tag_IsBox(v8::Local<v8::Value> val);
 
   void Neon_Error_NewError(v8::Local<v8::Value> *out, v8::Local<v8::String> msg);
   void Neon_Error_NewTypeError(v8::Local<v8::Value> *out, v8::Local<v8::String> msg);
@@ -126,6 +235,7 @@ extern "C" {
   void Neon_Error_NewRangeError(v8::Local<v8::Value> *out, v8::Local<v8::String> msg);
   void Neon_Error_NewSyntaxError(v8::Local<v8::Value> *out, v8::Local<v8::String> msg);
   void Neon_Error_Throw(v8::Local<v8::Value> val);
+  bool Neon_Error_TryCatch(v8::Local<v8::Value> *out, bool (*callback)(void *), void *data);
   void Neon_Error_ThrowErrorFromCString(const char *msg);
   void Neon_Error_ThrowTypeErrorFromCString(const char *msg);
   void Neon_Error_ThrowReferenceErrorFromCString(const char *msg);
@@ -133,11 +243,43 @@ extern "C" {
   void Neon_Error_ThrowSyntaxErrorFromCString(const char *msg);
 
   bool Neon_Mem_SameHandle(v8::Local<v8::Value> v1, v8::Local<v8::Value> v2);
+  void *Neon_Mem_NewPersistent(v8::Local<v8::Value> h);
+  void Neon_Mem_ReadPersistent(v8::Local<v8::Value> *out, void *persistent);
+  void Neon_Mem_DropPersistent(void *persistent);
+
+  bool Neon_Value_StrictEquals(v8::Local<v8::Value> v1, v8::Local<v8::Value> v2);
+  bool Neon_Value_LooseEquals(bool *out, v8::Local<v8::Value> v1, v8::Local<v8::Value> v2);
+  bool Neon_Value_SameValue(v8::Local<v8::Value> v1, v8::Local<v8::Value> v2);
+  Neon_TypeOf Neon_Value_TypeOf(v8::Local<v8::Value> val);
+  bool Neon_Value_InstanceOf(bool *out, v8::Local<v8::Value> val, v8::Local<v8::Object> ctor);
+
+  void Neon_Iterator_Symbol(v8::Local<v8::Symbol> *out, v8::Isolate *isolate);
+  bool Neon_Iterator_Get(v8::Local<v8::Object> *iterator, v8::Local<v8::Function> *next, v8::Local<v8::Value> iterable);
+  bool Neon_Iterator_Next(v8::Local<v8::Value> *out_value, bool *out_done, v8::Local<v8::Object> iterator, v8::Local<v8::Function> next);
+  bool Neon_Iterator_Close(v8::Local<v8::Object> iterator);
+  void Neon_Iterator_CloseAfterThrow(v8::Local<v8::Object> iterator);
+
+  bool Neon_JSON_Parse(v8::Local<v8::Value> *out, v8::Local<v8::String> text);
+  bool Neon_JSON_Stringify(v8::Local<v8::String> *out, v8::Local<v8::Value> value, v8::Local<v8::String> gap);
+
+  typedef bool (*Neon_WriteHostCallback)(void *host, v8::Local<v8::Object> object, const uint8_t **out_data, size_t *out_len);
+  typedef bool (*Neon_ReadHostCallback)(void *host, const uint8_t *data, size_t len, v8::Local<v8::Value> *out);
+
+  bool Neon_Serialize_Write(uint8_t **out_data, size_t *out_len, v8::Isolate *isolate, v8::Local<v8::Value> value, void *host, Neon_WriteHostCallback write_host);
+  void Neon_Serialize_FreeBuffer(uint8_t *data);
+  bool Neon_Serialize_Read(v8::Local<v8::Value> *out, v8::Isolate *isolate, const uint8_t *data, size_t len, void *host, Neon_ReadHostCallback read_host);
 
   typedef void* (*Neon_TaskPerformCallback)(void *);
   typedef void (*Neon_TaskCompleteCallback)(void *, void *, v8::Local<v8::Value> *out);
 
   void Neon_Task_Schedule(void *task, Neon_TaskPerformCallback perform, Neon_TaskCompleteCallback complete, v8::Local<v8::Function> callback);
+  void *Neon_Task_ScheduleCancellable(void *task, Neon_TaskPerformCallback perform, Neon_TaskCompleteCallback complete, v8::Local<v8::Function> callback);
+  bool Neon_Task_Cancel(void *request);
+
+  typedef void (*Neon_FutureCompleteCallback)(void *, v8::Local<v8::Value> *out);
+
+  bool Neon_Future_New(v8::Local<v8::Promise> *out, void **out_handle, void *future, Neon_FutureCompleteCallback complete);
+  void Neon_Future_Send(void *handle);
 }
 
 #endif
@@ -0,0 +1,97 @@
+#ifndef NEON_FUTURE_H_
+#define NEON_FUTURE_H_
+
+#include <uv.h>
+#include "neon.h"
+#include "v8.h"
+
+namespace neon {
+
+// A promise that is settled on the main thread when a Rust future, running on
+// some other thread, completes. The uv_async_t handle keeps the event loop
+// alive until then.
+class Future {
+public:
+  Future(v8::Isolate *isolate,
+         void *rust_future,
+         Neon_FutureCompleteCallback complete,
+         v8::Local<v8::Promise::Resolver> resolver)
+    : isolate_(isolate),
+      rust_future_(rust_future),
+      complete_(complete)
+  {
+    async_.data = this;
+    resolver_.Reset(isolate, resolver);
+    // Save the context (aka realm) to be used when settling the promise.
+    context_.Reset(isolate, isolate->GetCurrentContext());
+  }
+
+  // Called from any thread, exactly once, when the Rust future is done.
+  void send() {
+    uv_async_send(&async_);
+  }
+
+  void complete() {
+    v8::Isolate::Scope isolate_scope(isolate_);
+    v8::HandleScope handle_scope(isolate_);
+    v8::Local<v8::Context> context = v8::Local<v8::Context>::New(isolate_, context_);
+    v8::Context::Scope context_scope(context);
+
+#if NODE_MAJOR_VERSION >= 10
+    // Runs the microtask queue when the scope exits, so promise reactions run promptly.
+    node::CallbackScope callback_scope(isolate_, v8::Object::New(isolate_), { 0, 0 });
+#endif
+
+    v8::Local<v8::Promise::Resolver> resolver = v8::Local<v8::Promise::Resolver>::New(isolate_, resolver_);
+
+    {
+      v8::TryCatch trycatch(isolate_);
+
+      v8::Local<v8::Value> completion;
+
+      complete_(rust_future_, &completion);
+
+      if (trycatch.HasCaught()) {
+        v8::Local<v8::Value> exception = trycatch.Exception();
+        trycatch.Reset();
+        resolver->Reject(context, exception).IsJust();
+      } else {
+        resolver->Resolve(context, completion).IsJust();
+      }
+    }
+
+#if NODE_MAJOR_VERSION < 10
+    isolate_->RunMicrotasks();
+#endif
+
+    resolver_.Reset();
+    context_.Reset();
+  }
+
+  uv_async_t async_;
+
+private:
+  v8::Isolate *isolate_;
+  void *rust_future_;
+  Neon_FutureCompleteCallback complete_;
+  v8::Persistent<v8::Promise::Resolver> resolver_;
+  v8::Persistent<v8::Context> context_;
+};
+
+void close_future(uv_handle_t *handle) {
+  delete static_cast<Future*>(handle->data);
+}
+
+void complete_future(uv_async_t *handle) {
+  Future *future = static_cast<Future*>(handle->data);
+  future->complete();
+  uv_close(reinterpret_cast<uv_handle_t*>(handle), close_future);
+}
+
+void start_future(Future *future) {
+  uv_async_init(uv_default_loop(), &future->async_, complete_future);
+}
+
+}
+
+#endif
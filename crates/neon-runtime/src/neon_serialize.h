@@ -0,0 +1,85 @@
+#ifndef NEON_SERIALIZE_H_
+#define NEON_SERIALIZE_H_
+
+#include "neon.h"
+#include "v8.h"
+
+// The ValueSerializer API first shipped in Node 8.
+#if V8_MAJOR_VERSION > 5 || (V8_MAJOR_VERSION == 5 && V8_MINOR_VERSION >= 8)
+#define NEON_HAS_VALUE_SERIALIZER 1
+
+namespace neon {
+
+class SerializerDelegate : public v8::ValueSerializer::Delegate {
+public:
+  SerializerDelegate(v8::Isolate *isolate, void *host, Neon_WriteHostCallback write_host)
+    : isolate_(isolate), serializer_(nullptr), host_(host), write_host_(write_host)
+  {
+  }
+
+  void SetSerializer(v8::ValueSerializer *serializer) {
+    serializer_ = serializer;
+  }
+
+  void ThrowDataCloneError(v8::Local<v8::String> message) override {
+    isolate_->ThrowException(v8::Exception::Error(message));
+  }
+
+  v8::Maybe<bool> WriteHostObject(v8::Isolate *isolate, v8::Local<v8::Object> object) override {
+    const uint8_t *data;
+    size_t len;
+    if (!write_host_(host_, object, &data, &len)) {
+      return v8::Nothing<bool>();
+    }
+    serializer_->WriteUint32(static_cast<uint32_t>(len));
+    serializer_->WriteRawBytes(data, len);
+    return v8::Just(true);
+  }
+
+private:
+  v8::Isolate *isolate_;
+  v8::ValueSerializer *serializer_;
+  void *host_;
+  Neon_WriteHostCallback write_host_;
+};
+
+class DeserializerDelegate : public v8::ValueDeserializer::Delegate {
+public:
+  DeserializerDelegate(void *host, Neon_ReadHostCallback read_host)
+    : deserializer_(nullptr), host_(host), read_host_(read_host)
+  {
+  }
+
+  void SetDeserializer(v8::ValueDeserializer *deserializer) {
+    deserializer_ = deserializer;
+  }
+
+  v8::MaybeLocal<v8::Object> ReadHostObject(v8::Isolate *isolate) override {
+    uint32_t len;
+    const void *data;
+    if (!deserializer_->ReadUint32(&len) || !deserializer_->ReadRawBytes(len, &data)) {
+      Nan::ThrowError("invalid host object data");
+      return v8::MaybeLocal<v8::Object>();
+    }
+    v8::Local<v8::Value> result;
+    if (!read_host_(host_, static_cast<const uint8_t *>(data), len, &result)) {
+      return v8::MaybeLocal<v8::Object>();
+    }
+    if (!result->IsObject()) {
+      Nan::ThrowTypeError("host object data must produce an object");
+      return v8::MaybeLocal<v8::Object>();
+    }
+    return result.As<v8::Object>();
+  }
+
+private:
+  v8::ValueDeserializer *deserializer_;
+  void *host_;
+  Neon_ReadHostCallback read_host_;
+};
+
+}; // end namespace neon
+
+#endif
+
+#endif
@@ -93,6 +93,34 @@ private:
   uint32_t cursor_;
 };
 
+// The data of an external string, which V8 reads in place and disposes of when the string is
+// garbage collected.
+class ExternalOneByteString : public v8::String::ExternalOneByteStringResource {
+public:
+  ExternalOneByteString(const char *data, size_t length, void *owner, Neon_DropCallback drop)
+    : data_(data), length_(length), owner_(owner), drop_(drop)
+  {
+  }
+
+  ~ExternalOneByteString() {
+    drop_(owner_);
+  }
+
+  const char *data() const override {
+    return data_;
+  }
+
+  size_t length() const override {
+    return length_;
+  }
+
+private:
+  const char *data_;
+  size_t length_;
+  void *owner_;
+  Neon_DropCallback drop_;
+};
+
 }; // end namespace neon
 
 #endif
@@ -31,6 +31,12 @@ public:
     result_ = perform_(rust_task_);
   }
 
+  // Cancels the task if it hasn't started running yet. The task still completes, but
+  // without having been performed, so its result is null.
+  bool cancel() {
+    return uv_cancel(reinterpret_cast<uv_req_t*>(&request_)) == 0;
+  }
+
   void complete() {
     // Ensure that we have all the proper scopes installed on the C++ stack before
     // invoking the callback, and use the context (i.e. realm) we saved with the task.
@@ -85,7 +91,7 @@ void execute_task(uv_work_t *request) {
   task->execute();
 }
 
-void complete_task(uv_work_t *request) {
+void complete_task(uv_work_t *request, int status) {
   Task *task = static_cast<Task*>(request->data);
   task->complete();
   delete task;
@@ -95,7 +101,7 @@ void queue_task(Task *task) {
   uv_queue_work(uv_default_loop(),
                 &task->request_,
                 execute_task,
-                (uv_after_work_cb)complete_task);
+                complete_task);
 }
 
 }
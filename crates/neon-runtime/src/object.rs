@@ -9,10 +9,18 @@ extern "C" {
     pub fn new(out: &mut Local);
 
     /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing the
-    /// names of the `v8::Object`'s own property names. Returns `false` if the result is empty.
+    /// names of the `v8::Object`'s own property names. Returns `false` if getting the names
+    /// threw an exception.
     #[link_name = "Neon_Object_GetOwnPropertyNames"]
     pub fn get_own_property_names(out: &mut Local, object: Local) -> bool;
 
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing the
+    /// property keys of the `v8::Object`, filtered according to the flags provided, with array
+    /// index keys converted to strings. Returns `false` if getting the keys threw an exception,
+    /// e.g. from a proxy trap.
+    #[link_name = "Neon_Object_GetPropertyNames"]
+    pub fn get_property_names(out: &mut Local, object: Local, own_only: bool, enumerable_only: bool, configurable_only: bool, include_symbols: bool, include_indices: bool) -> bool;
+
     /// Gets the `v8::Isolate` of a `v8::Object`.
     #[link_name = "Neon_Object_GetIsolate"]
     pub fn get_isolate(obj: Local) -> *mut Isolate;
@@ -48,4 +56,92 @@ extern "C" {
     #[link_name = "Neon_Object_Set"]
     pub fn set(out: &mut bool, object: Local, key: Local, val: Local) -> bool;
 
+    /// Mutates the `out` argument provided to refer to a `v8::Local` boolean value, `true` if the
+    /// `v8::Object` or its prototype chain has a property at the `key` provided. Returns `false`
+    /// if the result couldn't be retrieved.
+    #[link_name = "Neon_Object_Has"]
+    pub fn has(out: &mut bool, object: Local, key: Local) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a `v8::Local` boolean value, `true` if the
+    /// `v8::Object` has an own property at the `key` provided. Returns `false` if the result
+    /// couldn't be retrieved.
+    #[link_name = "Neon_Object_HasOwn"]
+    pub fn has_own(out: &mut bool, object: Local, key: Local) -> bool;
+
+    /// Deletes the property of a `v8::Object` at the `key` provided. Also mutates the `out`
+    /// argument provided to refer to a `v8::Local` boolean value, `true` if the delete was
+    /// successful.
+    #[link_name = "Neon_Object_Delete"]
+    pub fn delete(out: &mut bool, object: Local, key: Local) -> bool;
+
+    /// Defines a data property of a `v8::Object` at the `key` provided, with the attributes
+    /// provided. Also mutates the `out` argument provided to refer to a `v8::Local` boolean
+    /// value, `true` if the definition was successful.
+    #[link_name = "Neon_Object_DefineDataProperty"]
+    pub fn define_data_property(out: &mut bool, object: Local, key: Local, val: Local, writable: bool, enumerable: bool, configurable: bool) -> bool;
+
+    /// Defines an accessor property of a `v8::Object` at the `key` provided, with the attributes
+    /// provided. Either of `getter` and `setter` may be `undefined`. Also mutates the `out`
+    /// argument provided to refer to a `v8::Local` boolean value, `true` if the definition was
+    /// successful.
+    #[link_name = "Neon_Object_DefineAccessorProperty"]
+    pub fn define_accessor_property(out: &mut bool, object: Local, key: Local, getter: Local, setter: Local, enumerable: bool, configurable: bool) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a descriptor object for the own property
+    /// of a `v8::Object` at the `key` provided, or `undefined` if there is no such property.
+    /// Returns `false` if the result couldn't be retrieved.
+    #[link_name = "Neon_Object_GetOwnPropertyDescriptor"]
+    pub fn get_own_property_descriptor(out: &mut Local, object: Local, key: Local) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Object` whose
+    /// prototype is `proto`, which must be an object or `null`. Returns `false` if the object
+    /// couldn't be created.
+    #[link_name = "Neon_Object_Create"]
+    pub fn create(out: &mut Local, proto: Local) -> bool;
+
+    /// Mutates the `out` argument provided to refer to the prototype of a `v8::Object`.
+    #[link_name = "Neon_Object_GetPrototype"]
+    pub fn get_prototype(out: &mut Local, object: Local);
+
+    /// Sets the prototype of a `v8::Object`, throwing a `TypeError` if `proto` is neither an
+    /// object nor `null`. Also mutates the `out` argument provided to refer to a `v8::Local`
+    /// boolean value, `true` if the prototype was set.
+    #[link_name = "Neon_Object_SetPrototype"]
+    pub fn set_prototype(out: &mut bool, object: Local, proto: Local) -> bool;
+
+    /// Freezes a `v8::Object`. Also mutates the `out` argument provided to refer to a `v8::Local`
+    /// boolean value, `true` if the object was frozen.
+    #[link_name = "Neon_Object_Freeze"]
+    pub fn freeze(out: &mut bool, object: Local) -> bool;
+
+    /// Seals a `v8::Object`. Also mutates the `out` argument provided to refer to a `v8::Local`
+    /// boolean value, `true` if the object was sealed.
+    #[link_name = "Neon_Object_Seal"]
+    pub fn seal(out: &mut bool, object: Local) -> bool;
+
+    /// Captures the built-in functions Neon calls for integrity operations, such as
+    /// `Object.isFrozen`, for the current context, unless they've already been captured. Returns
+    /// `false` if an exception was thrown while capturing them.
+    #[link_name = "Neon_Object_CaptureBuiltins"]
+    pub fn capture_builtins(isolate: *mut Isolate) -> bool;
+
+    /// Mutates the `out` argument provided to refer to the built-in function with the given
+    /// UTF-8 name captured for the current context, capturing the built-in functions first if
+    /// needed. Throws a `TypeError` and returns `false` if the function isn't available.
+    #[link_name = "Neon_Object_GetBuiltin"]
+    pub fn get_builtin(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: i32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Object` with a data
+    /// property for each of the `len` keys and values. The keys are given as UTF-8 buffers with
+    /// their byte lengths, and are created as internalized strings. Returns `false` if the object
+    /// couldn't be created.
+    #[link_name = "Neon_Object_FromEntries"]
+    pub fn from_entries(out: &mut Local, isolate: *mut Isolate, keys: *const *const u8, key_lens: *const i32, values: *const Local, len: u32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Object` with a data
+    /// property for each of the `len` elements of the `keys` array and the corresponding element
+    /// of `values`. Returns `false` if the object couldn't be created.
+    #[link_name = "Neon_Object_FromKeys"]
+    pub fn from_keys(out: &mut Local, isolate: *mut Isolate, keys: Local, values: *const Local, len: u32) -> bool;
+
 }
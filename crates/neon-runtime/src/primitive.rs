@@ -38,6 +38,11 @@ extern "C" {
     #[link_name = "Neon_Primitive_IntegerValue"]
     pub fn integer_value(p: Local) -> i64;
 
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Integer` object
+    /// holding a 32-bit unsigned integer.
+    #[link_name = "Neon_Primitive_Uint32"]
+    pub fn uint32(out: &mut Local, isolate: *mut Isolate, x: u32);
+
     /// Mutates the `out` argument provided to refer to a newly created `v8::Number` object.
     #[link_name = "Neon_Primitive_Number"]
     pub fn number(out: &mut Local, isolate: *mut Isolate, v: f64);
@@ -0,0 +1,19 @@
+//! Facilities for working with `v8::Promise`s.
+
+use raw::Local;
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to a `v8::Promise` resolved with `value`,
+    /// like the JS `Promise.resolve` function. If `value` is a thenable, the promise follows its
+    /// state. Returns `false` if the operation threw.
+    #[link_name = "Neon_Promise_Resolve"]
+    pub fn resolve(out: &mut Local, value: Local) -> bool;
+
+    /// Attaches the `on_fulfilled` and `on_rejected` handlers to a promise, like the built-in
+    /// `Promise.prototype.then`, mutating the `out` argument provided to refer to the promise it
+    /// returns. Returns `false` if attaching the handlers threw.
+    #[link_name = "Neon_Promise_Then"]
+    pub fn then(out: &mut Local, promise: Local, on_fulfilled: Local, on_rejected: Local) -> bool;
+
+}
@@ -0,0 +1,21 @@
+//! Facilities for working with `v8::RegExp`s.
+
+use raw::Local;
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::RegExp` with the
+    /// given `pattern` and `v8::RegExp::Flags` bitmask. Returns `false` if the value couldn't be
+    /// created, e.g. because the pattern is invalid.
+    #[link_name = "Neon_RegExp_New"]
+    pub fn new(out: &mut Local, pattern: Local, flags: u32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to the source text of a `v8::RegExp`.
+    #[link_name = "Neon_RegExp_Source"]
+    pub fn source(out: &mut Local, regexp: Local);
+
+    /// Gets the `v8::RegExp::Flags` bitmask of a `v8::RegExp`.
+    #[link_name = "Neon_RegExp_Flags"]
+    pub fn flags(regexp: Local) -> u32;
+
+}
@@ -0,0 +1,33 @@
+//! Facilities for serializing values with the V8 structured clone format.
+
+use std::os::raw::c_void;
+use raw::{Isolate, Local};
+
+/// Writes the data for a host object (an instance of a native class). Mutates `out_data` and
+/// `out_len` to refer to the data, which must stay valid until the next call, and returns
+/// `false` if an exception was thrown.
+pub type WriteHostCallback = extern "C" fn(host: *mut c_void, object: Local, out_data: &mut *const u8, out_len: &mut usize) -> bool;
+
+/// Recreates a host object from the data written by a `WriteHostCallback`. Mutates `out` to
+/// refer to the object and returns `false` if an exception was thrown.
+pub type ReadHostCallback = extern "C" fn(host: *mut c_void, data: *const u8, len: usize, out: &mut Local) -> bool;
+
+extern "C" {
+
+    /// Serializes `value`, mutating `out_data` and `out_len` to refer to a buffer that must be
+    /// released with `free_buffer`. Host objects are written with `write_host`, which is passed
+    /// `host`. Returns `false` if an exception was thrown.
+    #[link_name = "Neon_Serialize_Write"]
+    pub fn write(out_data: &mut *mut u8, out_len: &mut usize, isolate: *mut Isolate, value: Local, host: *mut c_void, write_host: WriteHostCallback) -> bool;
+
+    /// Releases a buffer produced by `write`.
+    #[link_name = "Neon_Serialize_FreeBuffer"]
+    pub fn free_buffer(data: *mut u8);
+
+    /// Mutates the `out` argument provided to refer to the value deserialized from `len` bytes
+    /// of `data`. Host objects are read with `read_host`, which is passed `host`. Returns `false`
+    /// if an exception was thrown.
+    #[link_name = "Neon_Serialize_Read"]
+    pub fn read(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: usize, host: *mut c_void, read_host: ReadHostCallback) -> bool;
+
+}
@@ -0,0 +1,38 @@
+//! Facilities for working with `v8::Set`s.
+
+use raw::{Local, Isolate};
+
+extern "C" {
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Set`.
+    #[link_name = "Neon_Set_New"]
+    pub fn new(out: &mut Local, isolate: *mut Isolate);
+
+    /// Gets the number of elements in a `v8::Set`.
+    #[link_name = "Neon_Set_Size"]
+    pub fn size(set: Local) -> u32;
+
+    /// Adds `val` to the `v8::Set`. Returns `false` if the operation threw.
+    #[link_name = "Neon_Set_Add"]
+    pub fn add(set: Local, val: Local) -> bool;
+
+    /// Mutates the `out` argument provided to indicate whether the `v8::Set` contains `val`.
+    /// Returns `false` if the operation threw.
+    #[link_name = "Neon_Set_Has"]
+    pub fn has(out: &mut bool, set: Local, val: Local) -> bool;
+
+    /// Removes `val` from the `v8::Set`, mutating the `out` argument provided to indicate whether
+    /// an element was removed. Returns `false` if the operation threw.
+    #[link_name = "Neon_Set_Delete"]
+    pub fn delete(out: &mut bool, set: Local, val: Local) -> bool;
+
+    /// Removes all elements from the `v8::Set`.
+    #[link_name = "Neon_Set_Clear"]
+    pub fn clear(set: Local);
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::Array` containing
+    /// the elements of the `v8::Set` in insertion order.
+    #[link_name = "Neon_Set_AsArray"]
+    pub fn as_array(out: &mut Local, set: Local);
+
+}
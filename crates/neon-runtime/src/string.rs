@@ -1,5 +1,6 @@
 //! Facilities for working with `v8::String`s.
 
+use std::os::raw::c_void;
 use raw::{Local, Isolate};
 
 extern "C" {
@@ -9,6 +10,48 @@ extern "C" {
     #[link_name = "Neon_String_New"]
     pub fn new(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: i32) -> bool;
 
+    /// Mutates the `out` argument provided to refer to an internalized `v8::String`, which V8
+    /// shares between all equal strings and can compare and use as a property key quickly.
+    /// Returns `false` if the value couldn't be created.
+    #[link_name = "Neon_String_NewInternalized"]
+    pub fn new_internalized(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: i32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::String` from `len`
+    /// UTF-16 code units. Returns `false` if the value couldn't be created.
+    #[link_name = "Neon_String_NewFromUtf16"]
+    pub fn new_from_utf16(out: &mut Local, isolate: *mut Isolate, data: *const u16, len: i32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created `v8::String` from `len`
+    /// Latin-1 bytes. Returns `false` if the value couldn't be created.
+    #[link_name = "Neon_String_NewFromOneByte"]
+    pub fn new_from_one_byte(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: i32) -> bool;
+
+    /// Mutates the `out` argument provided to refer to a newly created external `v8::String`,
+    /// whose `len` Latin-1 characters are read directly from `data` instead of being copied.
+    /// When the string is garbage collected, or if it couldn't be created, `drop` is called with
+    /// `owner`. Returns `false` if the value couldn't be created.
+    #[link_name = "Neon_String_NewExternal"]
+    pub fn new_external(out: &mut Local, isolate: *mut Isolate, data: *const u8, len: usize, owner: *mut c_void, drop: extern "C" fn(*mut c_void)) -> bool;
+
+    /// Gets the length of a `v8::String` in UTF-16 code units.
+    #[link_name = "Neon_String_Length"]
+    pub fn length(str: Local) -> i32;
+
+    /// Indicates if every character of a `v8::String` can be represented in a single byte
+    /// (i.e. is in the Latin-1 range).
+    #[link_name = "Neon_String_IsOneByte"]
+    pub fn is_one_byte(str: Local) -> bool;
+
+    /// Writes up to `len` UTF-16 code units of a `v8::String` and returns the number of code
+    /// units written. Lone surrogates are written as they are.
+    #[link_name = "Neon_String_WriteUtf16"]
+    pub fn write_utf16(out: *mut u16, len: i32, str: Local) -> i32;
+
+    /// Writes up to `len` characters of a `v8::String` as single bytes and returns the number of
+    /// bytes written. Characters outside the Latin-1 range are truncated to their low byte.
+    #[link_name = "Neon_String_WriteOneByte"]
+    pub fn write_one_byte(out: *mut u8, len: i32, str: Local) -> i32;
+
     /// Gets the length of a `v8::String`.
     #[link_name = "Neon_String_Utf8Length"]
     pub fn utf8_len(str: Local) -> isize;
@@ -2,8 +2,32 @@
 
 use raw::Local;
 
+/// A classification of a value into one of the types that Neon distinguishes.
+///
+/// The corresponding C++ enum `Neon_Tag` is defined in neon.h.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tag {
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    Array,
+    Function,
+    Buffer,
+    ArrayBuffer,
+    Error,
+    Object,
+    Other
+}
+
 extern "C" {
 
+    /// Classifies the value with a single call, rather than testing for each type in turn.
+    #[link_name = "Neon_Tag_Of"]
+    pub fn of(val: Local) -> Tag;
+
     /// Indicates if the value type is `Undefined`.
     #[link_name = "Neon_Tag_IsUndefined"]
     pub fn is_undefined(val: Local) -> bool;
@@ -32,10 +56,26 @@ extern "C" {
     #[link_name = "Neon_Tag_IsArray"]
     pub fn is_array(val: Local) -> bool;
 
+    /// Indicates if the value type is `Map`.
+    #[link_name = "Neon_Tag_IsMap"]
+    pub fn is_map(val: Local) -> bool;
+
+    /// Indicates if the value type is `Set`.
+    #[link_name = "Neon_Tag_IsSet"]
+    pub fn is_set(val: Local) -> bool;
+
+    /// Indicates if the value type is `Promise`.
+    #[link_name = "Neon_Tag_IsPromise"]
+    pub fn is_promise(val: Local) -> bool;
+
     /// Indicates if the value type is `Function`.
     #[link_name = "Neon_Tag_IsFunction"]
     pub fn is_function(val: Local) -> bool;
 
+    /// Indicates if the value type is `RegExp`.
+    #[link_name = "Neon_Tag_IsRegExp"]
+    pub fn is_regexp(val: Local) -> bool;
+
     /// Indicates if the value type is `Error`.
     #[link_name = "Neon_Tag_IsError"]
     pub fn is_error(val: Local) -> bool;
@@ -48,4 +88,8 @@ extern "C" {
     #[link_name = "Neon_Tag_IsArrayBuffer"]
     pub fn is_arraybuffer(obj: Local) -> bool;
 
+    /// Indicates if the value is an opaque object created with `Neon_Box_New`.
+    #[link_name = "Neon_Tag_IsBox"]
+    pub fn is_box(val: Local) -> bool;
+
 }
@@ -12,4 +12,18 @@ extern "C" {
                     complete: unsafe extern fn(*mut c_void, *mut c_void, &mut Local),
                     callback: Local);
 
+    /// Schedules a background task that can be cancelled, returning a handle to the request for
+    /// `cancel`. The handle is only valid until the task's `complete` callback is called. If the
+    /// task is cancelled before it starts, `complete` is called with a null result.
+    #[link_name = "Neon_Task_ScheduleCancellable"]
+    pub fn schedule_cancellable(task: *mut c_void,
+                                perform: unsafe extern "C" fn(*mut c_void) -> *mut c_void,
+                                complete: unsafe extern "C" fn(*mut c_void, *mut c_void, &mut Local),
+                                callback: Local) -> *mut c_void;
+
+    /// Cancels a scheduled task, if it hasn't started running yet. Returns `false` if the task
+    /// is already running or done. Must be called on the main thread.
+    #[link_name = "Neon_Task_Cancel"]
+    pub fn cancel(request: *mut c_void) -> bool;
+
 }
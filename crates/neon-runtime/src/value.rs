@@ -0,0 +1,46 @@
+//! Facilities for comparing and classifying `v8::Value`s.
+
+use raw::Local;
+
+/// The result of the JavaScript `typeof` operator.
+///
+/// The corresponding C++ enum `Neon_TypeOf` is defined in neon.h.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TypeOf {
+    Undefined,
+    Object,
+    Boolean,
+    Number,
+    String,
+    Symbol,
+    Function,
+    BigInt
+}
+
+extern "C" {
+
+    /// Indicates if two values are equal according to the JavaScript `===` operator.
+    #[link_name = "Neon_Value_StrictEquals"]
+    pub fn strict_equals(v1: Local, v2: Local) -> bool;
+
+    /// Mutates the `out` argument provided to indicate if two values are equal according to the
+    /// JavaScript `==` operator. Returns `false` if the comparison threw.
+    #[link_name = "Neon_Value_LooseEquals"]
+    pub fn loose_equals(out: &mut bool, v1: Local, v2: Local) -> bool;
+
+    /// Indicates if two values are equal according to the JavaScript `Object.is` function.
+    #[link_name = "Neon_Value_SameValue"]
+    pub fn same_value(v1: Local, v2: Local) -> bool;
+
+    /// Gets the result of applying the JavaScript `typeof` operator to a value.
+    #[link_name = "Neon_Value_TypeOf"]
+    pub fn type_of(val: Local) -> TypeOf;
+
+    /// Mutates the `out` argument provided to indicate if the value is an instance of the
+    /// constructor `ctor` according to the JavaScript `instanceof` operator. Returns `false` if
+    /// the check threw.
+    #[link_name = "Neon_Value_InstanceOf"]
+    pub fn instance_of(out: &mut bool, val: Local, ctor: Local) -> bool;
+
+}
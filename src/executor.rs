@@ -0,0 +1,114 @@
+//! The executor that drives Rust futures on behalf of JavaScript, such as the futures passed to
+//! `JsPromise::from_future` or returned by functions exported with
+//! `ModuleContext::export_async_function`.
+//!
+//! By default, futures are polled on a single background thread, started the first time a
+//! future is spawned. This is enough for futures that are woken by other threads, such as
+//! channels or the futures produced by `JsPromise::to_future`, but not for futures that rely on
+//! an I/O reactor or timers. With the `tokio` feature enabled, futures are spawned onto a Tokio
+//! runtime instead: the one provided with `set_runtime`, or else a multi-threaded runtime
+//! created the first time a future is spawned.
+
+use std::future::Future;
+
+/// Spawns a future onto the executor. The future runs to completion in the background; it's
+/// dropped if it panics.
+pub fn spawn<F: Future<Output = ()> + Send + 'static>(future: F) {
+    imp::spawn(future)
+}
+
+/// Makes the executor spawn futures onto the Tokio runtime with the given handle, instead of
+/// creating its own runtime. Returns `false` if the executor already has a runtime, because this
+/// was called before or a future was already spawned.
+#[cfg(feature = "tokio")]
+pub fn set_runtime(handle: ::tokio::runtime::Handle) -> bool {
+    imp::RUNTIME.set(handle).is_ok()
+}
+
+#[cfg(feature = "tokio")]
+mod imp {
+    use std::future::Future;
+    use std::sync::OnceLock;
+    use tokio::runtime::{Builder, Handle, Runtime};
+
+    pub(super) static RUNTIME: OnceLock<Handle> = OnceLock::new();
+
+    pub(super) fn spawn<F: Future<Output = ()> + Send + 'static>(future: F) {
+        let handle = RUNTIME.get_or_init(|| {
+            let runtime = Builder::new_multi_thread()
+                .enable_all()
+                .thread_name("neon-tokio")
+                .build()
+                .expect("failed to start the Tokio runtime");
+            // The runtime lives as long as the process, like the Node event loop.
+            let runtime: &'static Runtime = Box::leak(Box::new(runtime));
+            runtime.handle().clone()
+        });
+        handle.spawn(future);
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+mod imp {
+    use std::future::Future;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::sync::mpsc::{channel, Sender};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread;
+
+    type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    // A spawned future, which is sent back to the executor thread each time it's woken.
+    struct Job {
+        future: Mutex<Option<BoxFuture>>
+    }
+
+    impl Wake for Job {
+        fn wake(self: Arc<Self>) {
+            schedule(self);
+        }
+    }
+
+    static QUEUE: Mutex<Option<Sender<Arc<Job>>>> = Mutex::new(None);
+
+    pub(super) fn spawn<F: Future<Output = ()> + Send + 'static>(future: F) {
+        schedule(Arc::new(Job {
+            future: Mutex::new(Some(Box::pin(future)))
+        }));
+    }
+
+    fn schedule(job: Arc<Job>) {
+        let mut queue = QUEUE.lock().unwrap_or_else(|e| e.into_inner());
+        let sender = queue.get_or_insert_with(start);
+        // The executor thread never exits, so the job can always be sent.
+        let _ = sender.send(job);
+    }
+
+    fn start() -> Sender<Arc<Job>> {
+        let (sender, receiver) = channel::<Arc<Job>>();
+        thread::Builder::new()
+            .name("neon-executor".to_string())
+            .spawn(move || {
+                for job in receiver {
+                    run(job);
+                }
+            })
+            .expect("failed to start the executor thread");
+        sender
+    }
+
+    fn run(job: Arc<Job>) {
+        let mut slot = job.future.lock().unwrap_or_else(|e| e.into_inner());
+        // The slot is empty if the future already completed, but was woken again.
+        if let Some(mut future) = slot.take() {
+            let waker = Waker::from(job.clone());
+            let mut cx = Context::from_waker(&waker);
+            match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(&mut cx))) {
+                Ok(Poll::Pending) => { *slot = Some(future); }
+                Ok(Poll::Ready(())) | Err(_) => { }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Types for exposing arbitrary Rust data to JavaScript as opaque objects.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Deref;
+use std::os::raw::c_void;
+use neon_runtime;
+use neon_runtime::raw;
+use mem::Managed;
+use vm::{Context, JsResult};
+use js::{Value, build};
+use js::internal::ValueInternal;
+
+// The layout of the data owned by a box. The `TypeId` comes first so that it can be
+// checked through a pointer to a box of unknown type.
+#[repr(C)]
+struct BoxedValue<T> {
+    type_id: TypeId,
+    value: T
+}
+
+extern "C" fn drop_boxed_value<T>(boxed: *mut c_void) {
+    let p: Box<BoxedValue<T>> = unsafe { Box::from_raw(mem::transmute(boxed)) };
+    mem::drop(p);
+}
+
+/// An opaque JavaScript object that owns a Rust value of type `T`.
+///
+/// A `JsBox` is a lightweight alternative to defining a class with `declare_types!` for values
+/// that JavaScript only needs to hold onto and pass back to Rust. The contents can be accessed
+/// immutably by dereferencing the box; use a type with interior mutability (such as `RefCell`)
+/// if the contents need to change.
+///
+/// The contents are dropped on the main thread when the object is garbage collected, so the
+/// `Drop` implementation of `T` must not call into JavaScript. As with all garbage collected
+/// values, there is no guarantee that the contents are dropped before the process exits.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::vm::{Context, FunctionContext, JsResult};
+/// use neon::js::boxed::JsBox;
+/// use neon::mem::Handle;
+///
+/// struct Cursor {
+///     position: usize
+/// }
+///
+/// fn open_cursor(mut cx: FunctionContext) -> JsResult<JsBox<Cursor>> {
+///     JsBox::new(&mut cx, Cursor { position: 0 })
+/// }
+///
+/// # use neon::js::JsNumber;
+/// fn cursor_position(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let cursor: Handle<JsBox<Cursor>> = cx.argument(0)?;
+///     let position = cursor.position as f64;
+///     Ok(cx.number(position))
+/// }
+/// ```
+#[repr(C)]
+pub struct JsBox<T: 'static> {
+    raw: raw::Local,
+    marker: PhantomData<T>
+}
+
+impl<T: 'static> Clone for JsBox<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T: 'static> Copy for JsBox<T> { }
+
+impl<T: 'static> JsBox<T> {
+
+    /// Constructs a new opaque object that takes ownership of `value`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: T) -> JsResult<'a, JsBox<T>> {
+        let boxed = Box::new(BoxedValue {
+            type_id: TypeId::of::<T>(),
+            value: value
+        });
+        let data: *mut c_void = Box::into_raw(boxed) as *mut c_void;
+        build(|out| {
+            unsafe {
+                neon_runtime::boxed::new(out, cx.isolate().to_raw(), data, drop_boxed_value::<T>)
+            }
+        })
+    }
+
+}
+
+impl<T: 'static> Deref for JsBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe {
+            let boxed: *const BoxedValue<T> = neon_runtime::boxed::get(self.raw) as *const BoxedValue<T>;
+            &(*boxed).value
+        }
+    }
+}
+
+impl<T: 'static> Managed for JsBox<T> {
+    fn to_raw(self) -> raw::Local { self.raw }
+
+    fn from_raw(h: raw::Local) -> Self {
+        JsBox {
+            raw: h,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<T: 'static> ValueInternal for JsBox<T> {
+    fn name() -> String { "Box".to_string() }
+
+    fn is_typeof<Other: Value>(other: Other) -> bool {
+        unsafe {
+            if !neon_runtime::tag::is_box(other.to_raw()) {
+                return false;
+            }
+            let type_id: *const TypeId = neon_runtime::boxed::get(other.to_raw()) as *const TypeId;
+            *type_id == TypeId::of::<T>()
+        }
+    }
+}
+
+impl<T: 'static> Value for JsBox<T> { }
@@ -292,9 +292,10 @@ pub(crate) trait ClassInternal: Class {
             }
 
             for (name, method) in descriptor.methods {
+                let method_name = cx.string(format!("{}.{}", class_name, name));
                 let method: Handle<JsValue> = build(|out| {
                     let callback = method.into_c_callback();
-                    neon_runtime::fun::new_template(out, isolate, callback)
+                    neon_runtime::fun::new_template(out, isolate, callback, method_name.to_raw())
                 })?;
                 if !neon_runtime::class::add_method(isolate, metadata_pointer, name.as_ptr(), name.len() as u32, method.to_raw()) {
                     return Err(Throw);
@@ -0,0 +1,213 @@
+//! Types representing the JavaScript keyed collection types, `Map` and `Set`.
+
+use std::mem;
+use neon_runtime;
+use neon_runtime::raw;
+use mem::{Handle, Managed};
+use vm::{Context, JsResult, VmResult, Throw};
+use vm::internal::Isolate;
+use js::{Value, Object, JsValue, JsArray, build};
+use js::internal::ValueInternal;
+
+/// The standard JS [`Map`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Map) type.
+///
+/// Unlike the `Object` trait's `get` and `set` methods, which operate on properties, the methods
+/// of this type operate on the map's entries, which can be keyed by arbitrary JS values.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsMap(raw::Local);
+
+impl JsMap {
+
+    /// Constructs a new, empty `Map` object.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Handle<'a, JsMap> {
+        JsMap::new_internal(cx.isolate())
+    }
+
+    pub(crate) fn new_internal<'a>(isolate: Isolate) -> Handle<'a, JsMap> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::map::new(&mut local, isolate.to_raw());
+            Handle::new_internal(JsMap(local))
+        }
+    }
+
+    /// Produces the number of entries in the map.
+    pub fn size(self) -> u32 {
+        unsafe {
+            neon_runtime::map::size(self.to_raw())
+        }
+    }
+
+    /// Looks up the value associated with `key`, producing `undefined` if there is no such entry.
+    pub fn get<'a, C: Context<'a>, K: Value>(self, _: &mut C, key: Handle<K>) -> JsResult<'a, JsValue> {
+        build(|out| { unsafe { neon_runtime::map::get(out, self.to_raw(), key.to_raw()) } })
+    }
+
+    /// Associates `val` with `key`, replacing any existing entry for `key`.
+    pub fn set<'a, C: Context<'a>, K: Value, V: Value>(self, _: &mut C, key: Handle<K>, val: Handle<V>) -> VmResult<()> {
+        if unsafe { neon_runtime::map::set(self.to_raw(), key.to_raw(), val.to_raw()) } {
+            Ok(())
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Indicates whether the map has an entry for `key`.
+    pub fn has<'a, C: Context<'a>, K: Value>(self, _: &mut C, key: Handle<K>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::map::has(&mut result, self.to_raw(), key.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Removes the entry for `key`, producing `true` if there was such an entry.
+    pub fn delete<'a, C: Context<'a>, K: Value>(self, _: &mut C, key: Handle<K>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::map::delete(&mut result, self.to_raw(), key.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Removes all entries from the map.
+    pub fn clear<'a, C: Context<'a>>(self, _: &mut C) {
+        unsafe {
+            neon_runtime::map::clear(self.to_raw());
+        }
+    }
+
+    /// Produces a snapshot of the map's entries as key/value pairs, in insertion order.
+    pub fn to_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<(Handle<'a, JsValue>, Handle<'a, JsValue>)>> {
+        let flattened = unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::map::as_array(&mut local, self.to_raw());
+            JsArray::from_raw(local)
+        };
+        let len = flattened.len();
+        let mut result = Vec::with_capacity((len / 2) as usize);
+        let mut i = 0;
+        while i + 1 < len {
+            let key = flattened.get(cx, i)?;
+            let value = flattened.get(cx, i + 1)?;
+            result.push((key, value));
+            i += 2;
+        }
+        Ok(result)
+    }
+
+}
+
+impl Managed for JsMap {
+    fn to_raw(self) -> raw::Local { self.0 }
+
+    fn from_raw(h: raw::Local) -> Self { JsMap(h) }
+}
+
+impl ValueInternal for JsMap {
+    fn name() -> String { "Map".to_string() }
+
+    fn is_typeof<Other: Value>(other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_map(other.to_raw()) }
+    }
+}
+
+impl Value for JsMap { }
+
+impl Object for JsMap { }
+
+/// The standard JS [`Set`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Set) type.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsSet(raw::Local);
+
+impl JsSet {
+
+    /// Constructs a new, empty `Set` object.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Handle<'a, JsSet> {
+        JsSet::new_internal(cx.isolate())
+    }
+
+    pub(crate) fn new_internal<'a>(isolate: Isolate) -> Handle<'a, JsSet> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::set::new(&mut local, isolate.to_raw());
+            Handle::new_internal(JsSet(local))
+        }
+    }
+
+    /// Produces the number of elements in the set.
+    pub fn size(self) -> u32 {
+        unsafe {
+            neon_runtime::set::size(self.to_raw())
+        }
+    }
+
+    /// Adds `val` to the set.
+    pub fn add<'a, C: Context<'a>, V: Value>(self, _: &mut C, val: Handle<V>) -> VmResult<()> {
+        if unsafe { neon_runtime::set::add(self.to_raw(), val.to_raw()) } {
+            Ok(())
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Indicates whether the set contains `val`.
+    pub fn has<'a, C: Context<'a>, V: Value>(self, _: &mut C, val: Handle<V>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::set::has(&mut result, self.to_raw(), val.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Removes `val` from the set, producing `true` if it was present.
+    pub fn delete<'a, C: Context<'a>, V: Value>(self, _: &mut C, val: Handle<V>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::set::delete(&mut result, self.to_raw(), val.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Removes all elements from the set.
+    pub fn clear<'a, C: Context<'a>>(self, _: &mut C) {
+        unsafe {
+            neon_runtime::set::clear(self.to_raw());
+        }
+    }
+
+    /// Produces a snapshot of the set's elements, in insertion order.
+    pub fn to_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<Handle<'a, JsValue>>> {
+        let elements = unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::set::as_array(&mut local, self.to_raw());
+            JsArray::from_raw(local)
+        };
+        elements.to_vec(cx)
+    }
+
+}
+
+impl Managed for JsSet {
+    fn to_raw(self) -> raw::Local { self.0 }
+
+    fn from_raw(h: raw::Local) -> Self { JsSet(h) }
+}
+
+impl ValueInternal for JsSet {
+    fn name() -> String { "Set".to_string() }
+
+    fn is_typeof<Other: Value>(other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_set(other.to_raw()) }
+    }
+}
+
+impl Value for JsSet { }
+
+impl Object for JsSet { }
@@ -0,0 +1,177 @@
+//! Types and functions for working with the JavaScript iteration protocol.
+
+use std::cell::RefCell;
+use std::mem;
+use std::os::raw::c_void;
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+use std::rc::Rc;
+use std::thread;
+use neon_runtime;
+use neon_runtime::raw;
+use mem::{Handle, Managed};
+use vm::{Context, VmResult, JsResult, Throw};
+use js::{Value, JsValue, JsObject, JsFunction, JsBoolean, JsUndefined, Object, IntoJs};
+use js::internal::boxed_callback;
+
+/// Creates a JS iterator object that lazily produces the items of a Rust iterator, so JS code
+/// can consume it with `for...of`, spread syntax or `Array.from`. Each call to the object's
+/// `next` method advances the Rust iterator by one item and converts the item to JS.
+///
+/// The Rust iterator is dropped as soon as it's exhausted, or when JS stops iterating early
+/// and calls the object's `return` method, as a `for...of` loop does on `break`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::vm::{Context, FunctionContext, JsResult};
+/// # use neon::js::JsObject;
+/// use neon::js::iter;
+///
+/// fn squares(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     iter::from_iter(&mut cx, (1..).map(|n: u32| n * n))
+/// }
+/// ```
+pub fn from_iter<'a, C, I>(cx: &mut C, iter: I) -> JsResult<'a, JsObject>
+    where C: Context<'a>,
+          I: Iterator + 'static,
+          I::Item: IntoJs
+{
+    let state = Rc::new(RefCell::new(Some(iter)));
+
+    let next_state = state.clone();
+    let next = JsFunction::from_closure(cx, boxed_callback(move |mut cx| {
+        // The borrow ends before the item is converted, in case the conversion calls into JS.
+        let item = match *next_state.borrow_mut() {
+            Some(ref mut iter) => iter.next(),
+            None => None
+        };
+        match item {
+            Some(item) => {
+                let value = item.into_js(&mut cx)?.upcast();
+                iter_result(&mut cx, value, false)
+            }
+            None => {
+                next_state.borrow_mut().take();
+                let value = JsUndefined::new_internal().upcast();
+                iter_result(&mut cx, value, true)
+            }
+        }
+    }))?;
+
+    let return_state = state;
+    let ret = JsFunction::from_closure(cx, boxed_callback(move |mut cx| {
+        return_state.borrow_mut().take();
+        let value = match cx.argument_opt(0) {
+            Some(value) => value,
+            None => JsUndefined::new_internal().upcast()
+        };
+        iter_result(&mut cx, value, true)
+    }))?;
+
+    // Like the iterators of builtin collections, the object is its own iterable.
+    let iterator = JsFunction::from_closure(cx, boxed_callback(|mut cx| {
+        Ok(cx.this().upcast())
+    }))?;
+
+    let symbol = iterator_symbol(cx);
+    let obj = JsObject::new(cx);
+    obj.set(cx, "next", next)?;
+    obj.set(cx, "return", ret)?;
+    obj.set(cx, symbol, iterator)?;
+    Ok(obj)
+}
+
+fn iter_result<'a, C: Context<'a>>(cx: &mut C, value: Handle<JsValue>, done: bool) -> JsResult<'a, JsValue> {
+    let done = JsBoolean::new(cx, done).upcast();
+    Ok(JsObject::from_entries(cx, &[("value", value), ("done", done)])?.upcast())
+}
+
+fn iterator_symbol<'a, C: Context<'a>>(cx: &mut C) -> Handle<'a, JsValue> {
+    let mut local: raw::Local = unsafe { mem::zeroed() };
+    unsafe { neon_runtime::iterator::symbol(&mut local, cx.isolate().to_raw()); }
+    Handle::new_internal(JsValue::from_raw(local))
+}
+
+struct Step<'b, C: 'b, F: 'b> {
+    cx: &'b mut C,
+    f: &'b mut F,
+    value: raw::Local,
+    result: Option<thread::Result<VmResult<bool>>>
+}
+
+// Calls the iteration callback for one value, recording its result. Returns `false` if the
+// callback threw or panicked, so that any pending exception is caught by the caller.
+extern "C" fn step<'a, C, F>(data: *mut c_void) -> bool
+    where C: Context<'a>,
+          F: FnMut(&mut C, Handle<'a, JsValue>) -> VmResult<bool>
+{
+    let step: &mut Step<C, F> = unsafe { &mut *(data as *mut Step<C, F>) };
+    let value = Handle::new_internal(JsValue::from_raw(step.value));
+    let cx = &mut *step.cx;
+    let f = &mut *step.f;
+    let result = catch_unwind(AssertUnwindSafe(|| f(cx, value)));
+    let ok = match result {
+        Ok(Ok(_)) => true,
+        _ => false
+    };
+    step.result = Some(result);
+    ok
+}
+
+pub(crate) fn iterate<'a, C, V, F>(cx: &mut C, iterable: Handle<V>, mut f: F) -> VmResult<()>
+    where C: Context<'a>,
+          V: Value,
+          F: FnMut(&mut C, Handle<'a, JsValue>) -> VmResult<bool>
+{
+    let mut iterator: raw::Local = unsafe { mem::zeroed() };
+    let mut next: raw::Local = unsafe { mem::zeroed() };
+    if !unsafe { neon_runtime::iterator::get(&mut iterator, &mut next, iterable.to_raw()) } {
+        return Err(Throw);
+    }
+    loop {
+        let mut value: raw::Local = unsafe { mem::zeroed() };
+        let mut done = false;
+        if !unsafe { neon_runtime::iterator::next(&mut value, &mut done, iterator, next) } {
+            return Err(Throw);
+        }
+        if done {
+            return Ok(());
+        }
+
+        let mut exception: raw::Local = unsafe { mem::zeroed() };
+        let (caught, result) = {
+            let mut step = Step {
+                cx: &mut *cx,
+                f: &mut f,
+                value: value,
+                result: None
+            };
+            let data = &mut step as *mut Step<C, F> as *mut c_void;
+            let caught = unsafe { neon_runtime::error::try_catch(&mut exception, self::step::<C, F>, data) };
+            (caught, step.result.take().unwrap())
+        };
+
+        match result {
+            Ok(Ok(true)) => { }
+            Ok(Ok(false)) => {
+                // The caller stopped early, so the iterator gets a chance to clean up.
+                return if unsafe { neon_runtime::iterator::close(iterator) } {
+                    Ok(())
+                } else {
+                    Err(Throw)
+                };
+            }
+            Ok(Err(Throw)) => {
+                unsafe { neon_runtime::iterator::close_after_throw(iterator); }
+                if caught {
+                    unsafe { neon_runtime::error::throw(exception); }
+                }
+                return Err(Throw);
+            }
+            Err(panic) => {
+                unsafe { neon_runtime::iterator::close_after_throw(iterator); }
+                resume_unwind(panic);
+            }
+        }
+    }
+}
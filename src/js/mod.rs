@@ -1,21 +1,34 @@
 //! Types and traits representing JavaScript values.
 
 pub mod binary;
+pub mod boxed;
 pub mod error;
 pub mod class;
+pub mod collection;
+pub mod regexp;
+pub mod property;
+pub mod iter;
+pub mod promise;
 
 use std::fmt;
+use std::string::FromUtf16Error;
 use std::mem;
+use std::ptr;
 use std::os::raw::c_void;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Drop};
 use neon_runtime;
 use neon_runtime::raw;
+use neon_runtime::value::TypeOf;
+use neon_runtime::convert::PrimitiveHint as RawHint;
 use mem::{Handle, Managed};
 use vm::{Context, VmGuard, FunctionContext, Callback, VmResult, Throw, JsResult, JsResultExt, This};
 use vm::internal::{Isolate, Pointer};
 use js::error::{JsError, Kind};
-use self::internal::{ValueInternal, SuperType, FunctionCallback};
+use js::binary::{JsBuffer, JsArrayBuffer};
+use js::property::{Properties, PropertyOptions};
+use js::boxed::JsBox;
+use self::internal::{ValueInternal, SuperType, PropertyKeyInternal, FunctionCallback, BoxedCallback, boxed_callback, invoke_closure};
 
 pub(crate) mod internal {
     use std::mem;
@@ -24,9 +37,11 @@ pub(crate) mod internal {
     use neon_runtime::raw;
     use mem::{Handle, Managed};
     use vm::{JsResult, CallbackInfo, FunctionContext, Callback};
+    use std::panic::AssertUnwindSafe;
     use js::error::convert_panics;
-    use js::JsObject;
-    use super::Value;
+    use js::{JsObject, JsValue, JsArray, Object};
+    use js::boxed::JsBox;
+    use super::{Value, PropertyKey};
 
     pub trait ValueInternal: Managed + 'static {
         fn name() -> String;
@@ -50,6 +65,11 @@ pub(crate) mod internal {
         fn upcast_internal(T) -> Self;
     }
 
+    pub trait PropertyKeyInternal: PropertyKey {
+        /// Converts the key to a JS value, for operations that take the key as a value.
+        unsafe fn to_key(self, out: &mut raw::Local, isolate: *mut raw::Isolate) -> bool;
+    }
+
     #[repr(C)]
     pub struct FunctionCallback<T: Value>(pub fn(FunctionContext) -> JsResult<T>);
 
@@ -71,6 +91,33 @@ pub(crate) mod internal {
             unsafe { mem::transmute(self.0) }
         }
     }
+
+    pub type BoxedCallback = Box<dyn for<'a> Fn(FunctionContext<'a>) -> JsResult<'a, JsValue>>;
+
+    pub fn boxed_callback<F>(f: F) -> BoxedCallback
+        where F: for<'a> Fn(FunctionContext<'a>) -> JsResult<'a, JsValue> + 'static
+    {
+        Box::new(f)
+    }
+
+    // The static callback of a function created with `JsFunction::from_closure`. Unlike a
+    // `FunctionCallback`, the function's data holds a `JsBox` that owns the closure, so the
+    // closure is dropped when the function is garbage collected.
+    pub extern "C" fn invoke_closure(info: &CallbackInfo) {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(|mut cx| {
+                let pair: Handle<JsArray> = Handle::new_internal(JsArray::from_raw(info.data().to_raw()));
+                let data = match pair.get(&mut cx, 0) {
+                    Ok(value) => value,
+                    Err(_) => { return; }
+                };
+                let callback: JsBox<BoxedCallback> = JsBox::from_raw(data.to_raw());
+                if let Ok(value) = convert_panics(AssertUnwindSafe(|| { (*callback)(cx) })) {
+                    info.set_return(value);
+                }
+            })
+        }
+    }
 }
 
 pub(crate) fn build<'a, T: Managed, F: FnOnce(&mut raw::Local) -> bool>(init: F) -> JsResult<'a, T> {
@@ -96,15 +143,161 @@ impl<T: Object> SuperType<T> for JsObject {
     }
 }
 
+/// The type of a JavaScript value, as reported by the JS `typeof` operator.
+///
+/// Note that, as with `typeof`, the type of `null` is `JsType::Object`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JsType {
+    Undefined,
+    Object,
+    Boolean,
+    Number,
+    String,
+    Symbol,
+    Function,
+    BigInt
+}
+
+/// A JavaScript value classified by its type, for matching on values that can be one of
+/// several types.
+///
+/// Values of other object types, such as `Map`, `Set` or `RegExp`, are classified as
+/// `JsVariant::Object` and can be further downcast from there. Primitive types that Neon
+/// does not represent, such as symbols, are classified as `JsVariant::Other`.
+///
+/// See `Handle::variant`.
+#[derive(Clone, Copy)]
+pub enum JsVariant<'a> {
+    Undefined(Handle<'a, JsUndefined>),
+    Null(Handle<'a, JsNull>),
+    Boolean(Handle<'a, JsBoolean>),
+    Number(Handle<'a, JsNumber>),
+    String(Handle<'a, JsString>),
+    Array(Handle<'a, JsArray>),
+    Function(Handle<'a, JsFunction>),
+    Buffer(Handle<'a, JsBuffer>),
+    ArrayBuffer(Handle<'a, JsArrayBuffer>),
+    Error(Handle<'a, JsError>),
+    Object(Handle<'a, JsObject>),
+    Other(Handle<'a, JsValue>)
+}
+
+/// The preferred type of a conversion with `Value::to_primitive`, which is passed as the hint
+/// argument to an object's `Symbol.toPrimitive` method.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PrimitiveHint {
+    Default,
+    Number,
+    String
+}
+
 /// The trait shared by all JavaScript values.
 pub trait Value: ValueInternal {
     fn to_string<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsString> {
         build(|out| { unsafe { neon_runtime::convert::to_string(out, self.to_raw()) } })
     }
 
+    /// Converts this value to a number, like the JS expression `Number(value)`. This can throw,
+    /// either because the value is a symbol or because an object's `valueOf` method throws.
+    fn to_number<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsNumber> {
+        build(|out| { unsafe { neon_runtime::convert::to_number(out, self.to_raw()) } })
+    }
+
+    /// Converts this value to a boolean according to its truthiness, like the JS expression
+    /// `Boolean(value)`.
+    fn to_boolean<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsBoolean> {
+        build(|out| { unsafe { neon_runtime::convert::to_boolean(out, self.to_raw()) } })
+    }
+
+    /// Converts this value to an object, like the JS expression `Object(value)`, wrapping
+    /// primitives in their wrapper objects. Throws a `TypeError` for `null` and `undefined`.
+    fn to_object<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsObject> {
+        let value = self.to_raw();
+        build(|out| { unsafe { neon_runtime::convert::to_object(out, &value) } })
+    }
+
+    /// Converts this value to a number and truncates it towards zero, producing `0` for `NaN`.
+    fn to_integer<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsNumber> {
+        build(|out| { unsafe { neon_runtime::convert::to_integer(out, self.to_raw()) } })
+    }
+
+    /// Converts this value to a signed 32-bit integer, like the JS expression `value | 0`.
+    fn to_int32<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsNumber> {
+        build(|out| { unsafe { neon_runtime::convert::to_int32(out, self.to_raw()) } })
+    }
+
+    /// Converts this value to an unsigned 32-bit integer, like the JS expression `value >>> 0`.
+    fn to_uint32<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, JsNumber> {
+        build(|out| { unsafe { neon_runtime::convert::to_uint32(out, self.to_raw()) } })
+    }
+
+    /// Converts this value to a primitive. Primitives are produced unchanged; objects are
+    /// converted with their `Symbol.toPrimitive` method if they have one, and otherwise with
+    /// their `valueOf` and `toString` methods, trying `toString` first if `hint` is
+    /// `PrimitiveHint::String`.
+    fn to_primitive<'a, C: Context<'a>>(self, _: &mut C, hint: PrimitiveHint) -> JsResult<'a, JsValue> {
+        let hint = match hint {
+            PrimitiveHint::Default => RawHint::Default,
+            PrimitiveHint::Number  => RawHint::Number,
+            PrimitiveHint::String  => RawHint::String
+        };
+        build(|out| { unsafe { neon_runtime::convert::to_primitive(out, self.to_raw(), hint) } })
+    }
+
     fn as_value<'a, C: Context<'a>>(self, _: &mut C) -> Handle<'a, JsValue> {
         JsValue::new_internal(self.to_raw())
     }
+
+    /// Compares this value to another with the JS `===` operator.
+    ///
+    /// Unlike `==` on handles, which only tests whether two handles refer to the same
+    /// value, this compares primitive values by their contents.
+    fn strict_equals<U: Value>(self, other: Handle<U>) -> bool {
+        unsafe { neon_runtime::value::strict_equals(self.to_raw(), other.to_raw()) }
+    }
+
+    /// Compares this value to another with the JS `==` operator. Since the comparison may
+    /// invoke `valueOf` or `toString` methods, it can throw.
+    fn loose_equals<'a, C: Context<'a>, U: Value>(self, _: &mut C, other: Handle<U>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::value::loose_equals(&mut result, self.to_raw(), other.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Compares this value to another with the JS `Object.is` function, which is like `===`
+    /// except that `NaN` is equal to itself and `+0` is not equal to `-0`.
+    fn same_value<U: Value>(self, other: Handle<U>) -> bool {
+        unsafe { neon_runtime::value::same_value(self.to_raw(), other.to_raw()) }
+    }
+
+    /// Produces the type of this value, as reported by the JS `typeof` operator.
+    fn type_of(self) -> JsType {
+        match unsafe { neon_runtime::value::type_of(self.to_raw()) } {
+            TypeOf::Undefined => JsType::Undefined,
+            TypeOf::Object    => JsType::Object,
+            TypeOf::Boolean   => JsType::Boolean,
+            TypeOf::Number    => JsType::Number,
+            TypeOf::String    => JsType::String,
+            TypeOf::Symbol    => JsType::Symbol,
+            TypeOf::Function  => JsType::Function,
+            TypeOf::BigInt    => JsType::BigInt
+        }
+    }
+
+    /// Tests whether this value is an instance of the constructor `ctor` with the JS `instanceof`
+    /// operator. This throws a `TypeError` if `ctor` is not callable, and can throw if `ctor`
+    /// has a custom `Symbol.hasInstance` method.
+    fn instance_of<'a, C: Context<'a>, F: Object>(self, _: &mut C, ctor: Handle<F>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::value::instance_of(&mut result, self.to_raw(), ctor.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
 }
 
 /// A JavaScript value of any type.
@@ -138,6 +331,22 @@ impl JsValue {
     pub(crate) fn new_internal<'a>(value: raw::Local) -> Handle<'a, JsValue> {
         Handle::new_internal(JsValue(value))
     }
+
+    // Describes the type of this value for error messages: the result of `typeof`, followed
+    // by a more specific kind in parentheses for objects that Neon distinguishes.
+    pub(crate) fn describe_type(self) -> String {
+        let kind = match JsValue::new_internal(self.0).variant() {
+            JsVariant::Null(_)        => "null",
+            JsVariant::Array(_)       => "Array",
+            JsVariant::Buffer(_)      => "Buffer",
+            JsVariant::ArrayBuffer(_) => "ArrayBuffer",
+            JsVariant::Error(_)       => "Error",
+            _ => {
+                return format!("{:?}", self.type_of()).to_lowercase();
+            }
+        };
+        format!("object ({})", kind)
+    }
 }
 
 /// The JavaScript `undefined` value.
@@ -309,14 +518,74 @@ impl JsString {
         }
     }
 
+    /// Produces the length of the string in UTF-16 code units, like the JS `length` property.
+    pub fn length(self) -> usize {
+        unsafe {
+            neon_runtime::string::length(self.to_raw()) as usize
+        }
+    }
+
+    /// Indicates whether every character of the string is in the Latin-1 range (U+0000 to
+    /// U+00FF), in which case V8 can store it with one byte per character.
+    pub fn is_one_byte(self) -> bool {
+        unsafe {
+            neon_runtime::string::is_one_byte(self.to_raw())
+        }
+    }
+
+    /// Converts the string to UTF-8. Lone surrogates are replaced with U+FFFD; use `try_value`
+    /// or `to_utf16` to detect or preserve them.
     pub fn value(self) -> String {
+        let mut buffer = String::new();
+        self.value_into(&mut buffer);
+        buffer
+    }
+
+    /// Appends the string to `buffer` as UTF-8, reusing the buffer's allocation when it has
+    /// enough capacity. Lone surrogates are replaced with U+FFFD.
+    pub fn value_into(self, buffer: &mut String) {
         unsafe {
-            let capacity = neon_runtime::string::utf8_len(self.to_raw());
-            let mut buffer: Vec<u8> = Vec::with_capacity(capacity as usize);
-            let p = buffer.as_mut_ptr();
-            mem::forget(buffer);
-            let len = neon_runtime::string::data(p, capacity, self.to_raw());
-            String::from_raw_parts(p, len as usize, capacity as usize)
+            let len = neon_runtime::string::utf8_len(self.to_raw());
+            let bytes = buffer.as_mut_vec();
+            let start = bytes.len();
+            bytes.reserve(len as usize);
+            let written = neon_runtime::string::data(bytes.as_mut_ptr().offset(start as isize), len, self.to_raw());
+            bytes.set_len(start + written as usize);
+        }
+    }
+
+    /// Converts the string to UTF-8, failing if it contains a lone surrogate instead of
+    /// replacing it as `value` does.
+    pub fn try_value(self) -> Result<String, FromUtf16Error> {
+        if self.is_one_byte() {
+            return Ok(self.value());
+        }
+        String::from_utf16(&self.to_utf16())
+    }
+
+    /// Produces the UTF-16 code units of the string, including any lone surrogates.
+    pub fn to_utf16(self) -> Vec<u16> {
+        unsafe {
+            let len = neon_runtime::string::length(self.to_raw());
+            let mut buffer: Vec<u16> = Vec::with_capacity(len as usize);
+            let written = neon_runtime::string::write_utf16(buffer.as_mut_ptr(), len, self.to_raw());
+            buffer.set_len(written as usize);
+            buffer
+        }
+    }
+
+    /// Produces the characters of the string as Latin-1 bytes, or `None` if the string contains
+    /// a character outside the Latin-1 range. This is faster than `value` for one-byte strings.
+    pub fn to_latin1(self) -> Option<Vec<u8>> {
+        if !self.is_one_byte() {
+            return None;
+        }
+        unsafe {
+            let len = neon_runtime::string::length(self.to_raw());
+            let mut buffer: Vec<u8> = Vec::with_capacity(len as usize);
+            let written = neon_runtime::string::write_one_byte(buffer.as_mut_ptr(), len, self.to_raw());
+            buffer.set_len(written as usize);
+            Some(buffer)
         }
     }
 
@@ -332,6 +601,70 @@ impl JsString {
         }
     }
 
+    /// Constructs a string from UTF-16 code units, which may include lone surrogates.
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        if val.len() > (::std::i32::MAX as usize) {
+            return Err(StringOverflow(val.len()));
+        }
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            if neon_runtime::string::new_from_utf16(&mut local, cx.isolate().to_raw(), val.as_ptr(), val.len() as i32) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Constructs a string from Latin-1 bytes, where each byte is one character. This is faster
+    /// than `new` because the bytes don't need to be decoded.
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> StringResult<'a> {
+        if val.len() > (::std::i32::MAX as usize) {
+            return Err(StringOverflow(val.len()));
+        }
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            if neon_runtime::string::new_from_one_byte(&mut local, cx.isolate().to_raw(), val.as_ptr(), val.len() as i32) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Constructs a string that refers to static data instead of copying it into the JS heap,
+    /// which saves memory for large embedded text. V8 can only refer to one-byte data, so a
+    /// string containing non-ASCII characters is copied as with `try_new`.
+    pub fn external<'a, C: Context<'a>>(cx: &mut C, val: &'static str) -> StringResult<'a> {
+        if !val.is_ascii() {
+            return JsString::try_new(cx, val);
+        }
+        JsString::new_external(cx.isolate(), val.as_ptr(), val.len(), ptr::null_mut(), drop_static_external)
+    }
+
+    /// Constructs a string that takes ownership of `val` instead of copying it into the JS heap.
+    /// The Rust string is dropped when the JS string is garbage collected. A string containing
+    /// non-ASCII characters is copied as with `try_new`.
+    pub fn external_string<'a, C: Context<'a>>(cx: &mut C, val: String) -> StringResult<'a> {
+        if !val.is_ascii() {
+            return JsString::try_new(cx, val);
+        }
+        let (data, len) = (val.as_ptr(), val.len());
+        let owner = Box::into_raw(Box::new(val)) as *mut c_void;
+        JsString::new_external(cx.isolate(), data, len, owner, drop_owned_external)
+    }
+
+    fn new_external<'a>(isolate: Isolate, data: *const u8, len: usize, owner: *mut c_void, drop: extern "C" fn(*mut c_void)) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            if neon_runtime::string::new_external(&mut local, isolate.to_raw(), data, len, owner, drop) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(len))
+            }
+        }
+    }
+
     pub(crate) fn new_internal<'a>(isolate: Isolate, val: &str) -> Option<Handle<'a, JsString>> {
         let (ptr, len) = match lower_str(val) {
             Some(pair) => pair,
@@ -348,6 +681,12 @@ impl JsString {
     }
 }
 
+extern "C" fn drop_static_external(_: *mut c_void) { }
+
+extern "C" fn drop_owned_external(owner: *mut c_void) {
+    unsafe { mem::drop(Box::from_raw(owner as *mut String)); }
+}
+
 /// A trait for invoking the JS `[[ToString]]` conversion protocol.
 pub trait ToJsString {
     /// Invoke the JS `[[ToString]]` conversion protocol.
@@ -369,6 +708,61 @@ impl<'b> ToJsString for &'b str {
     }
 }
 
+/// A Rust value that can be converted to a JavaScript value.
+pub trait IntoJs {
+    /// The type of the JavaScript value produced by the conversion.
+    type Output: Value;
+
+    /// Converts this value to a JavaScript value.
+    fn into_js<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, Self::Output>;
+}
+
+impl<'b, V: Value> IntoJs for Handle<'b, V> {
+    type Output = V;
+
+    fn into_js<'a, C: Context<'a>>(self, _: &mut C) -> JsResult<'a, V> {
+        Ok(Handle::new_internal(V::from_raw(self.to_raw())))
+    }
+}
+
+impl IntoJs for bool {
+    type Output = JsBoolean;
+
+    fn into_js<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsBoolean> {
+        Ok(cx.boolean(self))
+    }
+}
+
+macro_rules! impl_into_js_number {
+    ($($t:ty),*) => { $(
+        impl IntoJs for $t {
+            type Output = JsNumber;
+
+            fn into_js<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsNumber> {
+                Ok(cx.number(self))
+            }
+        }
+    )* }
+}
+
+impl_into_js_number!(f64, f32, i32, u32, i16, u16, i8, u8);
+
+impl<'b> IntoJs for &'b str {
+    type Output = JsString;
+
+    fn into_js<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsString> {
+        JsString::try_new(cx, self).unwrap_or_throw(cx)
+    }
+}
+
+impl IntoJs for String {
+    type Output = JsString;
+
+    fn into_js<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsString> {
+        JsString::try_new(cx, self).unwrap_or_throw(cx)
+    }
+}
+
 // Lower a &str to the types expected by Node: a const *uint8_t buffer and an int32_t length.
 fn lower_str(s: &str) -> Option<(*const u8, i32)> {
     // V8 currently refuses to allocate strings longer than `(1 << 20) - 16` bytes,
@@ -388,6 +782,9 @@ fn lower_str_unwrap(s: &str) -> (*const u8, i32) {
     })
 }
 
+// The largest integer `n` such that `n` and `n + 1` are both exactly representable as an `f64`.
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
 /// A JavaScript number value.
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -406,11 +803,96 @@ impl JsNumber {
         }
     }
 
+    /// Constructs a number from a 32-bit signed integer, without converting it to a
+    /// floating-point number first.
+    pub fn from_i32<'a, C: Context<'a>>(cx: &mut C, x: i32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::primitive::integer(&mut local, cx.isolate().to_raw(), x);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    /// Constructs a number from a 32-bit unsigned integer, without converting it to a
+    /// floating-point number first.
+    pub fn from_u32<'a, C: Context<'a>>(cx: &mut C, x: u32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::primitive::uint32(&mut local, cx.isolate().to_raw(), x);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
     pub fn value(self) -> f64 {
         unsafe {
             neon_runtime::primitive::number_value(self.to_raw())
         }
     }
+
+    /// Indicates whether this number is an integer in the range of `i32`.
+    pub fn is_int32(self) -> bool {
+        unsafe { neon_runtime::primitive::is_i32(self.to_raw()) }
+    }
+
+    /// Indicates whether this number is an integer in the range of `u32`.
+    pub fn is_uint32(self) -> bool {
+        unsafe { neon_runtime::primitive::is_u32(self.to_raw()) }
+    }
+
+    /// Indicates whether this number is an integer that can be represented exactly, like the
+    /// JS `Number.isSafeInteger` function.
+    pub fn is_safe_integer(self) -> bool {
+        let v = self.value();
+        v.trunc() == v && v.abs() <= MAX_SAFE_INTEGER
+    }
+
+    /// Produces the value of this number as an `i32`, throwing if it is not an integer or is
+    /// out of range.
+    pub fn to_i32<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<i32> {
+        self.to_integer_in_range(cx, "i32", -2147483648.0, 2147483648.0).map(|v| v as i32)
+    }
+
+    /// Produces the value of this number as a `u32`, throwing if it is not an integer or is
+    /// out of range.
+    pub fn to_u32<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<u32> {
+        self.to_integer_in_range(cx, "u32", 0.0, 4294967296.0).map(|v| v as u32)
+    }
+
+    /// Produces the value of this number as an `i64`, throwing if it is not an integer or is
+    /// out of range.
+    pub fn to_i64<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<i64> {
+        self.to_integer_in_range(cx, "i64", -9223372036854775808.0, 9223372036854775808.0).map(|v| v as i64)
+    }
+
+    /// Produces the value of this number as a `u64`, throwing if it is not an integer or is
+    /// out of range.
+    pub fn to_u64<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<u64> {
+        self.to_integer_in_range(cx, "u64", 0.0, 18446744073709551616.0).map(|v| v as u64)
+    }
+
+    /// Produces the value of this number as a `usize`, throwing if it is not an integer or is
+    /// out of range.
+    pub fn to_usize<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<usize> {
+        // `usize::MAX + 1` is a power of two, so it is exact as an `f64` even when
+        // `usize::MAX` itself is not.
+        let end = (usize::max_value() as f64) + 1.0;
+        self.to_integer_in_range(cx, "usize", 0.0, end).map(|v| v as usize)
+    }
+
+    // Checks that the number is an integer in the half-open range `[start, end)`. Throws a
+    // `TypeError` if it is NaN, infinite or fractional and a `RangeError` if it is out of range.
+    fn to_integer_in_range<'a, C: Context<'a>>(self, cx: &mut C, ty: &str, start: f64, end: f64) -> VmResult<f64> {
+        let v = self.value();
+        if !v.is_finite() || v.trunc() != v {
+            let msg = format!("expected an integer, got {}", v);
+            return JsError::throw(cx, Kind::TypeError, &msg);
+        }
+        if v < start || v >= end {
+            let msg = format!("{} is out of range for {}", v, ty);
+            return JsError::throw(cx, Kind::RangeError, &msg);
+        }
+        Ok(v)
+    }
 }
 
 impl Value for JsNumber { }
@@ -470,6 +952,13 @@ impl PropertyKey for u32 {
     }
 }
 
+impl PropertyKeyInternal for u32 {
+    unsafe fn to_key(self, out: &mut raw::Local, isolate: *mut raw::Isolate) -> bool {
+        neon_runtime::primitive::uint32(out, isolate, self);
+        true
+    }
+}
+
 impl<'a, K: Value> PropertyKey for Handle<'a, K> {
     unsafe fn get_from(self, out: &mut raw::Local, obj: raw::Local) -> bool {
         neon_runtime::object::get(out, obj, self.to_raw())
@@ -480,6 +969,13 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
     }
 }
 
+impl<'a, K: Value> PropertyKeyInternal for Handle<'a, K> {
+    unsafe fn to_key(self, out: &mut raw::Local, _: *mut raw::Isolate) -> bool {
+        *out = self.to_raw();
+        true
+    }
+}
+
 impl<'a> PropertyKey for &'a str {
     unsafe fn get_from(self, out: &mut raw::Local, obj: raw::Local) -> bool {
         let (ptr, len) = lower_str_unwrap(self);
@@ -492,6 +988,65 @@ impl<'a> PropertyKey for &'a str {
     }
 }
 
+impl<'a> PropertyKeyInternal for &'a str {
+    unsafe fn to_key(self, out: &mut raw::Local, isolate: *mut raw::Isolate) -> bool {
+        let (ptr, len) = lower_str_unwrap(self);
+        neon_runtime::string::new(out, isolate, ptr, len)
+    }
+}
+
+/// A description of a property, as used by the JS `Object.defineProperty` and
+/// `Object.getOwnPropertyDescriptor` functions.
+#[derive(Clone, Copy)]
+pub enum PropertyDescriptor<'a> {
+    /// A property that holds a value.
+    Data {
+        value: Handle<'a, JsValue>,
+        writable: bool,
+        enumerable: bool,
+        configurable: bool
+    },
+    /// A property whose value is computed by a getter and assigned by a setter. An absent
+    /// getter behaves as if it returns `undefined`, and an absent setter ignores assignments.
+    Accessor {
+        get: Option<Handle<'a, JsFunction>>,
+        set: Option<Handle<'a, JsFunction>>,
+        enumerable: bool,
+        configurable: bool
+    }
+}
+
+fn lower_key<'a, C: Context<'a>, K: PropertyKeyInternal>(cx: &mut C, key: K) -> VmResult<raw::Local> {
+    unsafe {
+        let mut local: raw::Local = mem::zeroed();
+        if key.to_key(&mut local, cx.isolate().to_raw()) {
+            Ok(local)
+        } else {
+            Err(Throw)
+        }
+    }
+}
+
+// V8 has no API for the remaining integrity operations, so they call the corresponding
+// built-in function, which the runtime captures for each context before user code can replace it.
+fn call_builtin<'a, C: Context<'a>, O: Object>(cx: &mut C, name: &str, obj: O) -> VmResult<bool> {
+    let isolate = cx.isolate().to_raw();
+    let function: Handle<JsFunction> = build(|out| unsafe {
+        neon_runtime::object::get_builtin(out, isolate, name.as_ptr(), name.len() as i32)
+    })?;
+    let obj: Handle<JsValue> = Handle::new_internal(JsValue::from_raw(obj.to_raw()));
+    let result = function.call(cx, JsUndefined::new_internal(), vec![obj])?;
+    match result.downcast::<JsBoolean>() {
+        Ok(b) => Ok(b.value()),
+        Err(_) => JsError::throw(cx, Kind::TypeError, &format!("{} did not return a boolean", name))
+    }
+}
+
+fn descriptor_flag<'a, C: Context<'a>>(cx: &mut C, desc: Handle<'a, JsObject>, name: &str) -> VmResult<bool> {
+    let flag = desc.get(cx, name)?;
+    Ok(flag.downcast::<JsBoolean>().map(|b| b.value()).unwrap_or(false))
+}
+
 /// The trait of all object types.
 pub trait Object: Value {
     fn get<'a, C: Context<'a>, K: PropertyKey>(self, _: &mut C, key: K) -> VmResult<Handle<'a, JsValue>> {
@@ -502,6 +1057,13 @@ pub trait Object: Value {
         build(|out| { unsafe { neon_runtime::object::get_own_property_names(out, self.to_raw()) } })
     }
 
+    /// Produces an iterator over the keys and values of this object's properties, filtered
+    /// according to `options`. Keys are strings or symbols; array index keys are produced as
+    /// strings, like the keys produced by the JS `Object.keys` function.
+    fn properties<'a, 'b, C: Context<'a>>(self, cx: &'b mut C, options: PropertyOptions) -> VmResult<Properties<'a, 'b, C>> {
+        Properties::new(cx, self, options)
+    }
+
     fn set<'a, C: Context<'a>, K: PropertyKey, W: Value>(self, _: &mut C, key: K, val: Handle<W>) -> VmResult<bool> {
         let mut result = false;
         if unsafe { key.set_from(&mut result, self.to_raw(), val.to_raw()) } {
@@ -510,6 +1072,256 @@ pub trait Object: Value {
             Err(Throw)
         }
     }
+
+    /// Indicates whether this object or its prototype chain has a property at `key`, like the
+    /// JS `in` operator.
+    fn has<'a, C: Context<'a>, K: PropertyKeyInternal>(self, cx: &mut C, key: K) -> VmResult<bool> {
+        let key = lower_key(cx, key)?;
+        let mut result = false;
+        if unsafe { neon_runtime::object::has(&mut result, self.to_raw(), key) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Indicates whether this object has an own property at `key`, ignoring its prototype chain.
+    fn has_own<'a, C: Context<'a>, K: PropertyKeyInternal>(self, cx: &mut C, key: K) -> VmResult<bool> {
+        let key = lower_key(cx, key)?;
+        let mut result = false;
+        if unsafe { neon_runtime::object::has_own(&mut result, self.to_raw(), key) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Deletes the property at `key`, like the JS `delete` operator. Produces `false` if the
+    /// property is non-configurable.
+    fn delete<'a, C: Context<'a>, K: PropertyKeyInternal>(self, cx: &mut C, key: K) -> VmResult<bool> {
+        let key = lower_key(cx, key)?;
+        let mut result = false;
+        if unsafe { neon_runtime::object::delete(&mut result, self.to_raw(), key) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Defines or redefines the property at `key`, like the JS `Object.defineProperty` function.
+    /// Produces `false` if the property could not be defined, e.g. because it already exists
+    /// and is non-configurable.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, ModuleContext, VmResult};
+    /// use neon::js::{JsObject, Object, PropertyDescriptor};
+    /// use neon::mem::Handle;
+    ///
+    /// fn define_constant(cx: &mut ModuleContext, obj: Handle<JsObject>) -> VmResult<bool> {
+    ///     let value = cx.number(42).upcast();
+    ///     obj.define_property(cx, "ANSWER", PropertyDescriptor::Data {
+    ///         value: value,
+    ///         writable: false,
+    ///         enumerable: true,
+    ///         configurable: false
+    ///     })
+    /// }
+    /// ```
+    fn define_property<'a, 'b, C: Context<'a>, K: PropertyKeyInternal>(self, cx: &mut C, key: K, desc: PropertyDescriptor<'b>) -> VmResult<bool> {
+        let key = lower_key(cx, key)?;
+        let mut result = false;
+        let ok = match desc {
+            PropertyDescriptor::Data { value, writable, enumerable, configurable } => unsafe {
+                neon_runtime::object::define_data_property(&mut result, self.to_raw(), key, value.to_raw(),
+                                                           writable, enumerable, configurable)
+            },
+            PropertyDescriptor::Accessor { get, set, enumerable, configurable } => {
+                let undefined = cx.undefined().to_raw();
+                let get = get.map(|f| f.to_raw()).unwrap_or(undefined);
+                let set = set.map(|f| f.to_raw()).unwrap_or(undefined);
+                unsafe {
+                    neon_runtime::object::define_accessor_property(&mut result, self.to_raw(), key, get, set,
+                                                                   enumerable, configurable)
+                }
+            }
+        };
+        if ok {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Produces a description of the own property at `key`, or `None` if there is no such
+    /// property, like the JS `Object.getOwnPropertyDescriptor` function.
+    fn get_own_property_descriptor<'a, C: Context<'a>, K: PropertyKeyInternal>(self, cx: &mut C, key: K) -> VmResult<Option<PropertyDescriptor<'a>>> {
+        let key = lower_key(cx, key)?;
+        let desc: Handle<JsValue> = build(|out| { unsafe {
+            neon_runtime::object::get_own_property_descriptor(out, self.to_raw(), key)
+        } })?;
+        let desc = match desc.downcast::<JsObject>() {
+            Ok(desc) => desc,
+            Err(_) => { return Ok(None); }
+        };
+        let enumerable = descriptor_flag(cx, desc, "enumerable")?;
+        let configurable = descriptor_flag(cx, desc, "configurable")?;
+        if desc.has_own(cx, "value")? {
+            let value = desc.get(cx, "value")?;
+            let writable = descriptor_flag(cx, desc, "writable")?;
+            Ok(Some(PropertyDescriptor::Data {
+                value: value,
+                writable: writable,
+                enumerable: enumerable,
+                configurable: configurable
+            }))
+        } else {
+            let get = desc.get(cx, "get")?.downcast::<JsFunction>().ok();
+            let set = desc.get(cx, "set")?.downcast::<JsFunction>().ok();
+            Ok(Some(PropertyDescriptor::Accessor {
+                get: get,
+                set: set,
+                enumerable: enumerable,
+                configurable: configurable
+            }))
+        }
+    }
+
+    /// Defines an accessor property at `key` whose value is computed by calling `getter` each
+    /// time it is read, and which is assigned by calling `setter` with the new value as its
+    /// argument. Like an accessor in an object literal, the property is enumerable and
+    /// configurable. The `this`-binding of both callbacks is the object being accessed.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult};
+    /// # use neon::js::{JsObject, Object};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use neon::js::JsNumber;
+    ///
+    /// fn counter(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let obj = cx.empty_object();
+    ///     let count = Rc::new(Cell::new(0.0));
+    ///     let (read, write) = (count.clone(), count.clone());
+    ///     obj.define_accessor(&mut cx, "count", move |mut cx| {
+    ///         Ok(cx.number(read.get()))
+    ///     }, move |mut cx| {
+    ///         write.set(cx.argument::<JsNumber>(0)?.value());
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(obj)
+    /// }
+    /// ```
+    fn define_accessor<'a, C, K, G, S, V>(self, cx: &mut C, key: K, getter: G, setter: S) -> VmResult<bool>
+        where C: Context<'a>,
+              K: PropertyKeyInternal,
+              G: for<'c> Fn(FunctionContext<'c>) -> JsResult<'c, V> + 'static,
+              S: for<'c> Fn(FunctionContext<'c>) -> VmResult<()> + 'static,
+              V: Value
+    {
+        let get = JsFunction::from_closure(cx, boxed_callback(move |cx| {
+            getter(cx).map(|v| v.upcast())
+        }))?;
+        let set = JsFunction::from_closure(cx, boxed_callback(move |cx| {
+            setter(cx)?;
+            Ok(JsUndefined::new_internal().upcast())
+        }))?;
+        self.define_property(cx, key, PropertyDescriptor::Accessor {
+            get: Some(get),
+            set: Some(set),
+            enumerable: true,
+            configurable: true
+        })
+    }
+
+    /// Defines a read-only accessor property at `key` whose value is computed by calling
+    /// `getter` each time it is read. Like `define_accessor`, the property is enumerable and
+    /// configurable, and assignments to it are ignored.
+    fn define_getter<'a, C, K, G, V>(self, cx: &mut C, key: K, getter: G) -> VmResult<bool>
+        where C: Context<'a>,
+              K: PropertyKeyInternal,
+              G: for<'c> Fn(FunctionContext<'c>) -> JsResult<'c, V> + 'static,
+              V: Value
+    {
+        let get = JsFunction::from_closure(cx, boxed_callback(move |cx| {
+            getter(cx).map(|v| v.upcast())
+        }))?;
+        self.define_property(cx, key, PropertyDescriptor::Accessor {
+            get: Some(get),
+            set: None,
+            enumerable: true,
+            configurable: true
+        })
+    }
+
+    /// Produces the prototype of this object, which is either an object or `null`.
+    fn get_prototype<'a, C: Context<'a>>(self, _: &mut C) -> Handle<'a, JsValue> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::object::get_prototype(&mut local, self.to_raw());
+            JsValue::new_internal(local)
+        }
+    }
+
+    /// Sets the prototype of this object to `proto`, which must be an object or `null`, like
+    /// the JS `Object.setPrototypeOf` function. Produces `false` if the prototype could not be
+    /// changed, e.g. because the object is not extensible.
+    fn set_prototype<'a, C: Context<'a>, P: Value>(self, _: &mut C, proto: Handle<P>) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::object::set_prototype(&mut result, self.to_raw(), proto.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Freezes this object, like the JS `Object.freeze` function, so that its properties can
+    /// no longer be added, removed or changed.
+    fn freeze<'a, C: Context<'a>>(self, _: &mut C) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::object::freeze(&mut result, self.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Seals this object, like the JS `Object.seal` function, so that its properties can no
+    /// longer be added or removed, but writable properties can still be changed.
+    fn seal<'a, C: Context<'a>>(self, _: &mut C) -> VmResult<bool> {
+        let mut result = false;
+        if unsafe { neon_runtime::object::seal(&mut result, self.to_raw()) } {
+            Ok(result)
+        } else {
+            Err(Throw)
+        }
+    }
+
+    /// Prevents new properties from being added to this object, like the JS
+    /// `Reflect.preventExtensions` function, returning `false` if the object refused, as a
+    /// proxy can.
+    fn prevent_extensions<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<bool> {
+        call_builtin(cx, "preventExtensions", self)
+    }
+
+    /// Indicates whether this object is frozen, like the JS `Object.isFrozen` function.
+    fn is_frozen<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<bool> {
+        call_builtin(cx, "isFrozen", self)
+    }
+
+    /// Indicates whether this object is sealed, like the JS `Object.isSealed` function.
+    fn is_sealed<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<bool> {
+        call_builtin(cx, "isSealed", self)
+    }
+
+    /// Indicates whether new properties can be added to this object, like the JS
+    /// `Object.isExtensible` function.
+    fn is_extensible<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<bool> {
+        call_builtin(cx, "isExtensible", self)
+    }
 }
 
 impl Object for JsObject { }
@@ -523,6 +1335,33 @@ impl JsObject {
         JsObject::build(|out| { unsafe { neon_runtime::object::new(out) } })
     }
 
+    /// Constructs a new object whose prototype is `proto`, like the JS `Object.create` function.
+    /// Throws a `TypeError` if `proto` is neither an object nor `null`.
+    pub fn create<'a, C: Context<'a>, P: Value>(_: &mut C, proto: Handle<P>) -> JsResult<'a, JsObject> {
+        build(|out| { unsafe { neon_runtime::object::create(out, proto.to_raw()) } })
+    }
+
+    /// Constructs a new object with a property for each key and value in `entries`, in a single
+    /// call into the VM. If a key appears more than once, the last value wins.
+    ///
+    /// To construct many objects with the same keys, use an
+    /// [`ObjectShape`](property/struct.ObjectShape.html), which creates the keys only once.
+    pub fn from_entries<'a, C: Context<'a>>(cx: &mut C, entries: &[(&str, Handle<JsValue>)]) -> JsResult<'a, JsObject> {
+        let len = slice_len(cx, entries.len())?;
+        let mut keys = Vec::with_capacity(entries.len());
+        let mut key_lens = Vec::with_capacity(entries.len());
+        let mut values = Vec::with_capacity(entries.len());
+        for &(key, value) in entries {
+            let (ptr, key_len) = lower_str_unwrap(key);
+            keys.push(ptr);
+            key_lens.push(key_len);
+            values.push(value.to_raw());
+        }
+        build(|out| { unsafe {
+            neon_runtime::object::from_entries(out, cx.isolate().to_raw(), keys.as_ptr(), key_lens.as_ptr(), values.as_ptr(), len)
+        } })
+    }
+
     pub(crate) fn build<'a, F: FnOnce(&mut raw::Local)>(init: F) -> Handle<'a, JsObject> {
         unsafe {
             let mut local: raw::Local = mem::zeroed();
@@ -551,6 +1390,143 @@ impl JsArray {
         }
     }
 
+    /// Constructs a new array containing the elements produced by an iterator of handles.
+    pub fn from_iter<'a, 'b, C, V, I>(cx: &mut C, iter: I) -> JsResult<'a, JsArray>
+        where C: Context<'a>,
+              V: Value + 'b,
+              I: IntoIterator<Item=Handle<'b, V>>
+    {
+        let array = JsArray::new(cx, 0);
+        for (i, value) in iter.into_iter().enumerate() {
+            // Checking the length the array would have keeps the index in range.
+            let len = slice_len(cx, i + 1)?;
+            array.set(cx, len - 1, value)?;
+        }
+        Ok(array)
+    }
+
+    /// Constructs a new array containing the conversions of the elements of a slice.
+    pub fn from_slice<'a, C: Context<'a>, T: IntoJs + Clone>(cx: &mut C, slice: &[T]) -> JsResult<'a, JsArray> {
+        let len = slice_len(cx, slice.len())?;
+        let array = JsArray::new(cx, len);
+        for (i, value) in slice.iter().enumerate() {
+            let value = value.clone().into_js(cx)?;
+            array.set(cx, i as u32, value)?;
+        }
+        Ok(array)
+    }
+
+    /// Constructs a new array containing the numbers in a slice. This is much faster than
+    /// setting the elements one by one.
+    pub fn from_f64_slice<'a, C: Context<'a>>(cx: &mut C, slice: &[f64]) -> JsResult<'a, JsArray> {
+        let len = slice_len(cx, slice.len())?;
+        build(|out| { unsafe {
+            neon_runtime::array::from_f64_slice(out, cx.isolate().to_raw(), slice.as_ptr(), len)
+        } })
+    }
+
+    /// Constructs a new array containing the integers in a slice. This is much faster than
+    /// setting the elements one by one.
+    pub fn from_i32_slice<'a, C: Context<'a>>(cx: &mut C, slice: &[i32]) -> JsResult<'a, JsArray> {
+        let len = slice_len(cx, slice.len())?;
+        build(|out| { unsafe {
+            neon_runtime::array::from_i32_slice(out, cx.isolate().to_raw(), slice.as_ptr(), len)
+        } })
+    }
+
+    /// Constructs a new array containing the integers in a slice. This is much faster than
+    /// setting the elements one by one.
+    pub fn from_u32_slice<'a, C: Context<'a>>(cx: &mut C, slice: &[u32]) -> JsResult<'a, JsArray> {
+        let len = slice_len(cx, slice.len())?;
+        build(|out| { unsafe {
+            neon_runtime::array::from_u32_slice(out, cx.isolate().to_raw(), slice.as_ptr(), len)
+        } })
+    }
+
+    /// Copies the elements of the array into a vector of numbers, throwing a `TypeError` if any
+    /// element is not a number. This is much faster than reading the elements one by one.
+    pub fn to_f64_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<f64>> {
+        self.copy_to_vec(cx, "number", |out, index, element, array, len| unsafe {
+            neon_runtime::array::to_f64_slice(out, index, element, array, len)
+        })
+    }
+
+    /// Copies the elements of the array into a vector of integers, throwing a `TypeError` if
+    /// any element is not an integer in the range of `i32`. This is much faster than reading
+    /// the elements one by one.
+    pub fn to_i32_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<i32>> {
+        self.copy_to_vec(cx, "32-bit integer", |out, index, element, array, len| unsafe {
+            neon_runtime::array::to_i32_slice(out, index, element, array, len)
+        })
+    }
+
+    /// Copies the elements of the array into a vector of integers, throwing a `TypeError` if
+    /// any element is not an integer in the range of `u32`. This is much faster than reading
+    /// the elements one by one.
+    pub fn to_u32_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<u32>> {
+        self.copy_to_vec(cx, "32-bit unsigned integer", |out, index, element, array, len| unsafe {
+            neon_runtime::array::to_u32_slice(out, index, element, array, len)
+        })
+    }
+
+    fn copy_to_vec<'a, C, T, F>(self, cx: &mut C, expected: &str, copy: F) -> VmResult<Vec<T>>
+        where C: Context<'a>,
+              F: FnOnce(*mut T, &mut u32, &mut raw::Local, raw::Local, u32) -> bool
+    {
+        let len = self.len();
+        let mut result = Vec::with_capacity(len as usize);
+        let mut index = len;
+        let mut element: raw::Local = unsafe { mem::zeroed() };
+        if !copy(result.as_mut_ptr(), &mut index, &mut element, self.to_raw(), len) {
+            if index == len {
+                return Err(Throw);
+            }
+            let element = JsValue::new_internal(element);
+            let msg = format!("element {}: expected {}, got {}", index, expected, element.describe_type());
+            return JsError::throw(cx, Kind::TypeError, &msg);
+        }
+        unsafe { result.set_len(len as usize); }
+        Ok(result)
+    }
+
+    /// Appends `value` to the end of the array, producing the new length of the array. Throws a
+    /// `TypeError` if the element can't be added, e.g. because the array is frozen.
+    pub fn push<'a, C: Context<'a>, V: Value>(self, cx: &mut C, value: Handle<V>) -> VmResult<u32> {
+        let len = self.len();
+        if len == ::std::u32::MAX {
+            return JsError::throw(cx, Kind::RangeError, "array is already at the maximum length");
+        }
+        if !self.set(cx, len, value)? {
+            let msg = format!("cannot add element {} to array", len);
+            return JsError::throw(cx, Kind::TypeError, &msg);
+        }
+        Ok(self.len())
+    }
+
+    /// Produces the element at index `i`, downcast to the type `V`. Throws a `TypeError` if the
+    /// element is not of type `V`.
+    pub fn get_as<'a, C: Context<'a>, V: Value>(self, cx: &mut C, i: u32) -> JsResult<'a, V> {
+        let value = self.get(cx, i)?;
+        match value.downcast() {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                let msg = format!("element {}: expected {}, got {}", i, V::name(), value.describe_type());
+                JsError::throw(cx, Kind::TypeError, &msg)
+            }
+        }
+    }
+
+    /// Produces an iterator over the elements of the array. Since reading an element can run
+    /// arbitrary code that changes the array, the length is re-checked before each element.
+    pub fn iter<'a, 'b, C: Context<'a>>(self, cx: &'b mut C) -> Elements<'a, 'b, C> {
+        Elements {
+            cx: cx,
+            array: Handle::new_internal(self),
+            index: 0,
+            done: false
+        }
+    }
+
     pub fn to_vec<'a, C: Context<'a>>(self, cx: &mut C) -> VmResult<Vec<Handle<'a, JsValue>>> {
         let mut result = Vec::with_capacity(self.len() as usize);
         let mut i = 0;
@@ -572,6 +1548,39 @@ impl JsArray {
     }
 }
 
+// Checks that a slice is short enough to be converted to an array.
+fn slice_len<'a, C: Context<'a>>(cx: &mut C, len: usize) -> VmResult<u32> {
+    if len > (::std::u32::MAX as usize) {
+        let msg = format!("{} elements exceeds the maximum array length", len);
+        return JsError::throw(cx, Kind::RangeError, &msg);
+    }
+    Ok(len as u32)
+}
+
+/// An iterator over the elements of an array, produced by `JsArray::iter`.
+pub struct Elements<'a, 'b, C: Context<'a> + 'b> {
+    cx: &'b mut C,
+    array: Handle<'a, JsArray>,
+    index: u32,
+    done: bool
+}
+
+impl<'a, 'b, C: Context<'a> + 'b> Iterator for Elements<'a, 'b, C> {
+    type Item = VmResult<Handle<'a, JsValue>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.index >= self.array.len() {
+            return None;
+        }
+        let result = self.array.get(self.cx, self.index);
+        self.index += 1;
+        if result.is_err() {
+            self.done = true;
+        }
+        Some(result)
+    }
+}
+
 impl Value for JsArray { }
 
 impl Managed for JsArray {
@@ -628,6 +1637,24 @@ impl JsFunction {
             }
         })
     }
+
+    // Creates a function that calls a closure, which is owned by the function.
+    pub(crate) fn from_closure<'a, C: Context<'a>>(cx: &mut C, f: BoxedCallback) -> JsResult<'a, JsFunction> {
+        // Like the data of a function created by `new`, the data pairs the closure with the
+        // function itself, for `callee_name`.
+        let callback = JsBox::new(cx, f)?;
+        let data = JsArray::new(cx, 2);
+        data.set(cx, 0, callback)?;
+        let function: Handle<JsFunction> = build(|out| {
+            unsafe {
+                let isolate: *mut c_void = mem::transmute(cx.isolate().to_raw());
+                let callback = invoke_closure as *mut c_void;
+                neon_runtime::fun::new_with_data(out, isolate, callback, data.to_raw())
+            }
+        })?;
+        data.set(cx, 1, function)?;
+        Ok(function)
+    }
 }
 
 impl<CL: Object> JsFunction<CL> {
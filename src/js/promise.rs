@@ -0,0 +1,352 @@
+//! Types for working with JavaScript promises from Rust.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::os::raw::c_void;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{self, Poll, Waker};
+use neon_runtime;
+use neon_runtime::raw;
+use executor;
+use mem::{Handle, Managed};
+use vm::{Context, FunctionContext, TaskContext, JsResult, VmResult, Throw};
+use js::{Value, Object, JsValue, JsFunction, JsUndefined, build};
+use js::error::{JsError, Kind, convert_panics};
+use js::internal::{ValueInternal, boxed_callback};
+
+/// The standard JS [`Promise`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Promise) type.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsPromise(raw::Local);
+
+/// The settled state of a promise: `Ok` with the value it was fulfilled with, or `Err` with the
+/// reason it was rejected for.
+pub type Settled<'a> = Result<Handle<'a, JsValue>, Handle<'a, JsValue>>;
+
+impl JsPromise {
+
+    /// Produces a promise resolved with `value`, like the JS `Promise.resolve` function. If the
+    /// value is a thenable, such as a promise from another library, the promise follows its
+    /// state, so this can be used to await any thenable.
+    pub fn resolve<'a, C: Context<'a>, V: Value>(_: &mut C, value: Handle<V>) -> JsResult<'a, JsPromise> {
+        build(|out| { unsafe { neon_runtime::promise::resolve(out, value.to_raw()) } })
+    }
+
+    /// Produces a promise that is settled when a Rust future completes. The future is spawned
+    /// onto the `executor`, and once it completes, its output is converted by `complete` on the
+    /// JS thread. The promise is resolved with the value `complete` returns, or rejected with
+    /// the exception it throws.
+    ///
+    /// If the future panics, or is dropped before completing, the promise is rejected with an
+    /// `Error`.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult};
+    /// # use neon::js::JsString;
+    /// # use neon::js::error::{JsError, Kind};
+    /// # use std::future::Future;
+    /// use neon::js::promise::JsPromise;
+    ///
+    /// # fn read_file(path: String) -> impl Future<Output = Result<String, String>> + Send { std::future::ready(Ok(path)) }
+    /// fn read(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let path = cx.argument::<JsString>(0)?.value();
+    ///     JsPromise::from_future(&mut cx, read_file(path), |mut cx, result| {
+    ///         match result {
+    ///             Ok(contents) => Ok(cx.string(contents)),
+    ///             Err(msg) => JsError::throw(&mut cx, Kind::Error, &msg)
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    pub fn from_future<'a, C, F, V, CF>(_: &mut C, future: F, complete: CF) -> JsResult<'a, JsPromise>
+        where C: Context<'a>,
+              F: Future + Send + 'static,
+              F::Output: Send + 'static,
+              V: Value,
+              CF: for<'b> FnOnce(TaskContext<'b>, F::Output) -> JsResult<'b, V> + 'static
+    {
+        let output = Arc::new(Mutex::new(None));
+        let data = Box::into_raw(Box::new(PendingFuture {
+            output: output.clone(),
+            complete: complete
+        })) as *mut c_void;
+        let mut handle: *mut c_void = ptr::null_mut();
+        let promise = build(|out| { unsafe {
+            neon_runtime::future::new(out, &mut handle, data, complete_future::<F::Output, V, CF>)
+        } });
+        if promise.is_err() {
+            drop(unsafe { Box::from_raw(data as *mut PendingFuture<F::Output, CF>) });
+            return Err(Throw);
+        }
+        executor::spawn(Settle {
+            future: Box::pin(future),
+            settlement: Some(Settlement { handle: handle, output: output })
+        });
+        promise
+    }
+
+    /// Calls `f` on the JS thread once the promise is settled, with the value it was fulfilled
+    /// with or the reason it was rejected for. Produces the promise returned by `then`, which is
+    /// resolved with the value `f` returns, or rejected if `f` throws.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult};
+    /// # use neon::js::{JsFunction, JsValue};
+    /// # use neon::mem::Handle;
+    /// use neon::js::promise::JsPromise;
+    ///
+    /// fn run_hook(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let hook: Handle<JsFunction> = cx.argument(0)?;
+    ///     let undefined = cx.undefined();
+    ///     let result = hook.call(&mut cx, undefined, Vec::<Handle<JsValue>>::new())?;
+    ///     let promise = JsPromise::resolve(&mut cx, result)?;
+    ///     promise.when_settled(&mut cx, |mut cx, settled| {
+    ///         match settled {
+    ///             Ok(_) => Ok(cx.string("done")),
+    ///             Err(_) => Ok(cx.string("failed"))
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    pub fn when_settled<'a, C, V, F>(self, cx: &mut C, f: F) -> JsResult<'a, JsPromise>
+        where C: Context<'a>,
+              V: Value,
+              F: for<'b> FnOnce(FunctionContext<'b>, Settled<'b>) -> JsResult<'b, V> + 'static
+    {
+        // Only one of the handlers is ever called, so they share the closure.
+        let on_fulfilled_f = Rc::new(RefCell::new(Some(f)));
+        let on_rejected_f = on_fulfilled_f.clone();
+
+        let on_fulfilled = JsFunction::from_closure(cx, boxed_callback(move |mut cx| {
+            let value = cx.argument_opt(0).unwrap_or_else(|| JsUndefined::new_internal().upcast());
+            let f = on_fulfilled_f.borrow_mut().take().expect("promise settled more than once");
+            f(cx, Ok(value)).map(|v| v.upcast())
+        }))?;
+
+        let on_rejected = JsFunction::from_closure(cx, boxed_callback(move |mut cx| {
+            let reason = cx.argument_opt(0).unwrap_or_else(|| JsUndefined::new_internal().upcast());
+            let f = on_rejected_f.borrow_mut().take().expect("promise settled more than once");
+            f(cx, Err(reason)).map(|v| v.upcast())
+        }))?;
+
+        let derived: Handle<JsValue> = build(|out| { unsafe {
+            neon_runtime::promise::then(out, self.to_raw(), on_fulfilled.to_raw(), on_rejected.to_raw())
+        } })?;
+        JsPromise::resolve(cx, derived)
+    }
+
+    /// Produces a Rust future that completes once the promise is settled. The settled value is
+    /// converted by `f` on the JS thread, since JS values can't leave it, and the future's output
+    /// is the result of the conversion.
+    ///
+    /// The future completes with `Canceled` if `f` panics, or if the promise is garbage
+    /// collected without ever being settled.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult};
+    /// # use neon::js::{JsFunction, JsString, JsUndefined, JsValue};
+    /// # use neon::mem::Handle;
+    /// use neon::js::promise::JsPromise;
+    ///
+    /// fn load_config(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let hook: Handle<JsFunction> = cx.argument(0)?;
+    ///     let undefined = cx.undefined();
+    ///     let result = hook.call(&mut cx, undefined, Vec::<Handle<JsValue>>::new())?;
+    ///     let promise = JsPromise::resolve(&mut cx, result)?;
+    ///     let config = promise.to_future(&mut cx, |_, settled| {
+    ///         settled.ok()
+    ///             .and_then(|v| v.downcast::<JsString>().ok())
+    ///             .map(|s| s.value())
+    ///     })?;
+    ///     // `config` can now be awaited by a Rust executor on another thread.
+    /// #   drop(config);
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn to_future<'a, C, O, F>(self, cx: &mut C, f: F) -> VmResult<JsFuture<O>>
+        where C: Context<'a>,
+              O: Send + 'static,
+              F: for<'b> FnOnce(&mut FunctionContext<'b>, Settled<'b>) -> O + 'static
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            output: None,
+            waker: None,
+            canceled: false
+        }));
+        let completion = Completion { shared: Some(shared.clone()) };
+        self.when_settled(cx, move |mut cx, settled| {
+            let output = f(&mut cx, settled);
+            completion.complete(output);
+            Ok(JsUndefined::new_internal())
+        })?;
+        Ok(JsFuture { shared: shared })
+    }
+
+}
+
+impl Managed for JsPromise {
+    fn to_raw(self) -> raw::Local { self.0 }
+
+    fn from_raw(h: raw::Local) -> Self { JsPromise(h) }
+}
+
+impl ValueInternal for JsPromise {
+    fn name() -> String { "Promise".to_string() }
+
+    fn is_typeof<Other: Value>(other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_promise(other.to_raw()) }
+    }
+}
+
+impl Value for JsPromise { }
+
+impl Object for JsPromise { }
+
+// The state of a promise created by `JsPromise::from_future`, which is owned by the JS thread.
+struct PendingFuture<T, CF> {
+    output: Arc<Mutex<Option<T>>>,
+    complete: CF
+}
+
+unsafe extern "C" fn complete_future<T, V, CF>(data: *mut c_void, out: &mut raw::Local)
+    where V: Value,
+          CF: for<'b> FnOnce(TaskContext<'b>, T) -> JsResult<'b, V>
+{
+    let pending: Box<PendingFuture<T, CF>> = Box::from_raw(data as *mut PendingFuture<T, CF>);
+    let PendingFuture { output, complete } = *pending;
+    let output = output.lock().unwrap_or_else(|e| e.into_inner()).take();
+    TaskContext::with(|mut cx| {
+        let result = match output {
+            Some(output) => convert_panics(AssertUnwindSafe(|| complete(cx, output).map(|v| v.upcast::<JsValue>()))),
+            None => JsError::throw(&mut cx, Kind::Error, "the future panicked or was dropped before completing")
+        };
+        if let Ok(value) = result {
+            *out = value.to_raw();
+        }
+    })
+}
+
+// The side of a promise created by `JsPromise::from_future` that is owned by the future. The
+// promise is settled when the future completes, or when it's dropped without completing.
+struct Settlement<T> {
+    handle: *mut c_void,
+    output: Arc<Mutex<Option<T>>>
+}
+
+// The handle is only used to wake the JS thread, which is thread-safe.
+unsafe impl<T: Send> Send for Settlement<T> { }
+
+impl<T> Settlement<T> {
+    fn send(self, output: T) {
+        *self.output.lock().unwrap_or_else(|e| e.into_inner()) = Some(output);
+    }
+}
+
+impl<T> Drop for Settlement<T> {
+    fn drop(&mut self) {
+        unsafe { neon_runtime::future::send(self.handle); }
+    }
+}
+
+struct Settle<F: Future> {
+    future: Pin<Box<F>>,
+    settlement: Option<Settlement<F::Output>>
+}
+
+impl<F: Future> Future for Settle<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<()> {
+        match self.future.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                if let Some(settlement) = self.settlement.take() {
+                    settlement.send(output);
+                }
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending
+        }
+    }
+}
+
+struct Shared<O> {
+    output: Option<O>,
+    waker: Option<Waker>,
+    canceled: bool
+}
+
+// The JS side of a `JsFuture`. If it's dropped without completing the future, because the
+// conversion panicked or the promise was collected, the future is canceled instead of hanging.
+struct Completion<O> {
+    shared: Option<Arc<Mutex<Shared<O>>>>
+}
+
+impl<O> Completion<O> {
+    fn complete(mut self, output: O) {
+        let shared = self.shared.take().unwrap();
+        let mut shared = shared.lock().unwrap();
+        shared.output = Some(output);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<O> Drop for Completion<O> {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.take() {
+            if let Ok(mut shared) = shared.lock() {
+                shared.canceled = true;
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+/// A Rust future that completes when a JS promise is settled, produced by
+/// `JsPromise::to_future`.
+pub struct JsFuture<O> {
+    shared: Arc<Mutex<Shared<O>>>
+}
+
+impl<O> Future for JsFuture<O> {
+    type Output = Result<O, Canceled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(output) = shared.output.take() {
+            return Poll::Ready(Ok(output));
+        }
+        if shared.canceled {
+            return Poll::Ready(Err(Canceled));
+        }
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// The error produced by a `JsFuture` whose promise callback never completed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Canceled;
+
+impl fmt::Display for Canceled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the promise callback did not complete")
+    }
+}
+
+impl Error for Canceled { }
@@ -0,0 +1,305 @@
+//! Types for enumerating and constructing the properties of JavaScript objects.
+
+use neon_runtime;
+use neon_runtime::raw;
+use mem::{Handle, Managed, Persistent};
+use vm::{Context, VmResult, JsResult};
+use vm::internal::Isolate;
+use js::{Object, PropertyKey, JsObject, JsArray, JsString, JsValue, build, lower_str_unwrap, slice_len};
+use js::error::{JsError, Kind};
+use js::internal::PropertyKeyInternal;
+
+/// Options controlling which properties are produced by `Object::properties`.
+///
+/// The default options produce the same keys as the JS `Object.keys` function: the object's
+/// own enumerable properties with string keys, including array indices.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::vm::{Context, FunctionContext, JsResult};
+/// # use neon::js::{JsNumber, JsObject, Object};
+/// use neon::js::property::PropertyOptions;
+/// use neon::mem::Handle;
+///
+/// fn count_inherited(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let obj: Handle<JsObject> = cx.argument(0)?;
+///     let options = PropertyOptions::new().own_only(false);
+///     let mut count = 0;
+///     for entry in obj.properties(&mut cx, options)? {
+///         let (_key, _value) = entry?;
+///         count += 1;
+///     }
+///     Ok(cx.number(count))
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PropertyOptions {
+    own_only: bool,
+    enumerable_only: bool,
+    configurable_only: bool,
+    include_symbols: bool,
+    include_indices: bool
+}
+
+impl PropertyOptions {
+
+    /// Constructs the default options.
+    pub fn new() -> Self {
+        PropertyOptions {
+            own_only: true,
+            enumerable_only: true,
+            configurable_only: false,
+            include_symbols: false,
+            include_indices: true
+        }
+    }
+
+    /// Sets whether to skip properties inherited from the prototype chain. Defaults to `true`.
+    pub fn own_only(mut self, own_only: bool) -> Self {
+        self.own_only = own_only;
+        self
+    }
+
+    /// Sets whether to skip non-enumerable properties. Defaults to `true`.
+    pub fn enumerable_only(mut self, enumerable_only: bool) -> Self {
+        self.enumerable_only = enumerable_only;
+        self
+    }
+
+    /// Sets whether to skip non-configurable properties. Defaults to `false`.
+    pub fn configurable_only(mut self, configurable_only: bool) -> Self {
+        self.configurable_only = configurable_only;
+        self
+    }
+
+    /// Sets whether to include properties with symbol keys. Defaults to `false`.
+    pub fn include_symbols(mut self, include_symbols: bool) -> Self {
+        self.include_symbols = include_symbols;
+        self
+    }
+
+    /// Sets whether to include properties with array index keys. Defaults to `true`.
+    pub fn include_indices(mut self, include_indices: bool) -> Self {
+        self.include_indices = include_indices;
+        self
+    }
+
+}
+
+impl Default for PropertyOptions {
+    fn default() -> Self { PropertyOptions::new() }
+}
+
+/// An iterator over the properties of an object, produced by `Object::properties`.
+///
+/// The keys are collected when the iterator is created, but each value is read as the
+/// iterator reaches it, so the iterator produces an error if reading a value throws.
+pub struct Properties<'a, 'b, C: Context<'a> + 'b> {
+    cx: &'b mut C,
+    object: Handle<'a, JsObject>,
+    keys: Handle<'a, JsArray>,
+    index: u32,
+    done: bool
+}
+
+impl<'a, 'b, C: Context<'a> + 'b> Properties<'a, 'b, C> {
+    pub(crate) fn new<O: Object>(cx: &'b mut C, object: O, options: PropertyOptions) -> VmResult<Self> {
+        let keys = build(|out| { unsafe {
+            neon_runtime::object::get_property_names(out, object.to_raw(),
+                                                     options.own_only,
+                                                     options.enumerable_only,
+                                                     options.configurable_only,
+                                                     options.include_symbols,
+                                                     options.include_indices)
+        } })?;
+        Ok(Properties {
+            cx: cx,
+            object: Handle::new_internal(JsObject::from_raw(object.to_raw())),
+            keys: keys,
+            index: 0,
+            done: false
+        })
+    }
+}
+
+impl<'a, 'b, C: Context<'a> + 'b> Iterator for Properties<'a, 'b, C> {
+    type Item = VmResult<(Handle<'a, JsValue>, Handle<'a, JsValue>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.index >= self.keys.len() {
+            return None;
+        }
+        let key = match self.keys.get(self.cx, self.index) {
+            Ok(key) => key,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+        self.index += 1;
+        match self.object.get(self.cx, key) {
+            Ok(value) => Some(Ok((key, value))),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// A fixed list of property keys for constructing many objects with the same properties.
+///
+/// The keys are created as JS strings the first time the shape is used in each isolate, and
+/// are reused after that, so each object is constructed in a single call into the VM.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::vm::{Context, FunctionContext, JsResult};
+/// # use neon::js::JsObject;
+/// use neon::js::property::ObjectShape;
+///
+/// fn point(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let shape = ObjectShape::new(&["x", "y"]);
+///     let x = cx.number(1).upcast();
+///     let y = cx.number(2).upcast();
+///     shape.build(&mut cx, &[x, y])
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ObjectShape {
+    keys: &'static [&'static str]
+}
+
+impl ObjectShape {
+
+    /// Constructs a shape with the given property keys. Shapes are identified by the address of
+    /// their keys, so constructing a shape from the same `'static` slice each time is cheap.
+    pub fn new(keys: &'static [&'static str]) -> Self {
+        ObjectShape {
+            keys: keys
+        }
+    }
+
+    /// Produces the property keys of the shape.
+    pub fn keys(&self) -> &'static [&'static str] {
+        self.keys
+    }
+
+    /// Constructs a new object with a property for each key of the shape, set to the
+    /// corresponding element of `values`. Throws a `RangeError` if the number of values doesn't
+    /// match the number of keys.
+    pub fn build<'a, C: Context<'a>>(&self, cx: &mut C, values: &[Handle<JsValue>]) -> JsResult<'a, JsObject> {
+        if values.len() != self.keys.len() {
+            let msg = format!("expected {} values for object shape, got {}", self.keys.len(), values.len());
+            return JsError::throw(cx, Kind::RangeError, &msg);
+        }
+        let keys = self.key_array(cx)?;
+        let values: Vec<_> = values.iter().map(|value| value.to_raw()).collect();
+        build(|out| { unsafe {
+            neon_runtime::object::from_keys(out, cx.isolate().to_raw(), keys.to_raw(), values.as_ptr(), values.len() as u32)
+        } })
+    }
+
+    fn key_array<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsArray> {
+        if let Some(keys) = cx.isolate().key_cache().get_shape(self.keys) {
+            return Ok(keys.get(cx));
+        }
+        let len = slice_len(cx, self.keys.len())?;
+        let array = JsArray::new(cx, len);
+        for (i, key) in self.keys.iter().enumerate() {
+            let (ptr, key_len) = lower_str_unwrap(key);
+            let key: Handle<JsValue> = build(|out| { unsafe {
+                neon_runtime::string::new_internalized(out, cx.isolate().to_raw(), ptr, key_len)
+            } })?;
+            array.set(cx, i as u32, key)?;
+        }
+        cx.isolate().key_cache().set_shape(self.keys, Persistent::new(array));
+        Ok(array)
+    }
+
+}
+
+/// A property name whose JS string is created once per isolate and reused for every read and
+/// write, avoiding the cost of creating a new string for each access with a `&str` key.
+/// Usually declared as a `static` with the `property_name!` macro.
+///
+/// # Example:
+///
+/// ```no_run
+/// # #[macro_use] extern crate neon;
+/// # use neon::vm::{Context, FunctionContext, JsResult};
+/// # use neon::js::{JsNumber, JsObject, Object};
+/// use neon::js::property::PropertyName;
+///
+/// static COUNT: PropertyName = property_name!("count");
+///
+/// fn increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let obj: neon::mem::Handle<JsObject> = cx.argument(0)?;
+///     let count = obj.get(&mut cx, COUNT)?.to_number(&mut cx)?.value() + 1.0;
+///     let count = cx.number(count);
+///     obj.set(&mut cx, COUNT, count)?;
+///     Ok(count)
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PropertyName {
+    name: &'static str
+}
+
+impl PropertyName {
+
+    /// Constructs a property name.
+    pub const fn new(name: &'static str) -> Self {
+        PropertyName {
+            name: name
+        }
+    }
+
+    /// Produces the name as a Rust string.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Produces the interned JS string for the name, like `Context::intern`.
+    pub fn to_js_string<'a, C: Context<'a>>(&self, cx: &mut C) -> Handle<'a, JsString> {
+        cx.intern(self.name)
+    }
+
+}
+
+impl PropertyKey for PropertyName {
+    unsafe fn get_from(self, out: &mut raw::Local, obj: raw::Local) -> bool {
+        let mut key: raw::Local = ::std::mem::zeroed();
+        intern_name(&mut key, Isolate::current(), self.name) && neon_runtime::object::get(out, obj, key)
+    }
+
+    unsafe fn set_from(self, out: &mut bool, obj: raw::Local, val: raw::Local) -> bool {
+        let mut key: raw::Local = ::std::mem::zeroed();
+        intern_name(&mut key, Isolate::current(), self.name) && neon_runtime::object::set(out, obj, key, val)
+    }
+}
+
+impl PropertyKeyInternal for PropertyName {
+    unsafe fn to_key(self, out: &mut raw::Local, isolate: *mut raw::Isolate) -> bool {
+        intern_name(out, Isolate::from_raw(isolate), self.name)
+    }
+}
+
+// Mutates `out` to refer to the interned string for `name`, creating it and adding it to the
+// isolate's key cache if this is the first time the name has been interned.
+pub(crate) unsafe fn intern_name(out: &mut raw::Local, mut isolate: Isolate, name: &str) -> bool {
+    if let Some(key) = isolate.key_cache().get_name(name) {
+        *out = key.read();
+        return true;
+    }
+    let (ptr, len) = lower_str_unwrap(name);
+    if !neon_runtime::string::new_internalized(out, isolate.to_raw(), ptr, len) {
+        return false;
+    }
+    let key: Handle<JsString> = Handle::new_internal(JsString::from_raw(*out));
+    isolate.key_cache().set_name(name, Persistent::new(key));
+    true
+}
@@ -0,0 +1,109 @@
+//! Types representing JavaScript regular expressions.
+
+use std::mem;
+use neon_runtime;
+use neon_runtime::raw;
+use mem::{Handle, Managed};
+use vm::{Context, JsResult, JsResultExt};
+use js::{Value, Object, JsString, build};
+use js::error::{JsError, Kind};
+use js::internal::ValueInternal;
+
+// The `v8::RegExp::Flags` bits, listed in the order that the JS `flags` getter produces them.
+const FLAGS: [(char, u32); 8] = [
+    ('d', 1 << 7),  // kHasIndices
+    ('g', 1 << 0),  // kGlobal
+    ('i', 1 << 1),  // kIgnoreCase
+    ('m', 1 << 2),  // kMultiline
+    ('s', 1 << 5),  // kDotAll
+    ('u', 1 << 4),  // kUnicode
+    ('v', 1 << 8),  // kUnicodeSets
+    ('y', 1 << 3)   // kSticky
+];
+
+const UNICODE: u32 = 1 << 4;
+const UNICODE_SETS: u32 = 1 << 8;
+
+fn parse_flags(flags: &str) -> Option<u32> {
+    let mut bits = 0;
+    for c in flags.chars() {
+        let bit = match FLAGS.iter().find(|&&(flag, _)| flag == c) {
+            Some(&(_, bit)) => bit,
+            None => { return None; }
+        };
+        if bits & bit != 0 {
+            return None;
+        }
+        bits |= bit;
+    }
+    // Like the JS constructor, reject the two Unicode modes together.
+    if bits & UNICODE != 0 && bits & UNICODE_SETS != 0 {
+        return None;
+    }
+    Some(bits)
+}
+
+/// The standard JS [`RegExp`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RegExp) type.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsRegExp(raw::Local);
+
+impl JsRegExp {
+
+    /// Constructs a new `RegExp` object, like the JS expression `new RegExp(pattern, flags)`.
+    ///
+    /// Throws a `SyntaxError` if the pattern is invalid or `flags` contains characters other than
+    /// `d`, `g`, `i`, `m`, `s`, `u`, `v` and `y`, contains any of them more than once, or
+    /// contains both `u` and `v`. The `d` and `v` flags require a version of V8 that supports
+    /// them (Node 16 and Node 20 respectively); older versions throw a `SyntaxError`.
+    pub fn new<'a, C: Context<'a>, S: AsRef<str>>(cx: &mut C, pattern: S, flags: &str) -> JsResult<'a, JsRegExp> {
+        let bits = match parse_flags(flags) {
+            Some(bits) => bits,
+            None => {
+                let msg = format!("Invalid flags supplied to RegExp constructor '{}'", flags);
+                return JsError::throw(cx, Kind::SyntaxError, &msg);
+            }
+        };
+        let pattern = JsString::try_new(cx, pattern).unwrap_or_throw(cx)?;
+        build(|out| { unsafe { neon_runtime::regexp::new(out, pattern.to_raw(), bits) } })
+    }
+
+    /// Produces the source text of the regular expression, i.e. the pattern without the
+    /// enclosing slashes or flags.
+    pub fn source<'a, C: Context<'a>>(self, _: &mut C) -> Handle<'a, JsString> {
+        unsafe {
+            let mut local: raw::Local = mem::zeroed();
+            neon_runtime::regexp::source(&mut local, self.to_raw());
+            Handle::new_internal(JsString::from_raw(local))
+        }
+    }
+
+    /// Produces the flags of the regular expression, in the same canonical order as the JS
+    /// `flags` property (e.g. `"gimsuy"`).
+    pub fn flags(self) -> String {
+        let bits = unsafe { neon_runtime::regexp::flags(self.to_raw()) };
+        FLAGS.iter()
+             .filter(|&&(_, bit)| bits & bit != 0)
+             .map(|&(flag, _)| flag)
+             .collect()
+    }
+
+}
+
+impl Managed for JsRegExp {
+    fn to_raw(self) -> raw::Local { self.0 }
+
+    fn from_raw(h: raw::Local) -> Self { JsRegExp(h) }
+}
+
+impl ValueInternal for JsRegExp {
+    fn name() -> String { "RegExp".to_string() }
+
+    fn is_typeof<Other: Value>(other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_regexp(other.to_raw()) }
+    }
+}
+
+impl Value for JsRegExp { }
+
+impl Object for JsRegExp { }
@@ -4,6 +4,9 @@ extern crate neon_runtime;
 extern crate cslice;
 extern crate semver;
 
+#[cfg(feature = "tokio")]
+extern crate tokio;
+
 #[cfg(test)]
 extern crate rustc_version;
 
@@ -16,6 +19,10 @@ pub mod vm;
 pub mod js;
 pub mod task;
 pub mod meta;
+pub mod serialize;
+pub mod executor;
+
+pub use serialize::{serialize, deserialize};
 
 #[doc(hidden)]
 pub mod macro_internal;
@@ -101,6 +108,23 @@ macro_rules! register_module {
     }
 }
 
+/// Declare a property name whose JS string is interned once per isolate, for use as a fast
+/// property key.
+///
+/// Example:
+///
+/// ```rust,ignore
+/// static NAME: PropertyName = property_name!("name");
+///
+/// let name = obj.get(&mut cx, NAME)?;
+/// ```
+#[macro_export]
+macro_rules! property_name {
+    ($name:expr) => {
+        $crate::js::property::PropertyName::new($name)
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! class_definition {
@@ -356,6 +380,40 @@ mod tests {
         run("cargo test --release", &project_root().join("test").join("static"));
     }
 
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio_executor_test() {
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::sync::mpsc::{channel, Sender};
+        use std::task::{Context, Poll};
+        use std::time::Duration;
+        use tokio::runtime::{Builder, Handle};
+        use executor;
+
+        // Reports whether it was polled from within a Tokio runtime.
+        struct InRuntime(Sender<bool>);
+
+        impl Future for InRuntime {
+            type Output = ();
+
+            fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<()> {
+                let _ = self.0.send(Handle::try_current().is_ok());
+                Poll::Ready(())
+            }
+        }
+
+        log("tokio_executor_test");
+
+        let (tx, rx) = channel();
+        executor::spawn(InRuntime(tx));
+        assert!(rx.recv_timeout(Duration::from_secs(10)).expect("the future was never polled"));
+
+        // The first spawn created the executor's runtime, so it can no longer be replaced.
+        let runtime = Builder::new_current_thread().build().unwrap();
+        assert!(!executor::set_runtime(runtime.handle().clone()));
+    }
+
     #[test]
     fn dynamic_test() {
         let _guard = TEST_MUTEX.lock();
@@ -4,9 +4,11 @@ use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::error::Error;
 use std::fmt::{self, Debug, Display};
+use std::os::raw::c_void;
 use neon_runtime;
 use neon_runtime::raw;
-use js::Value;
+use neon_runtime::tag::Tag;
+use js::{Value, JsVariant};
 use js::internal::SuperType;
 use js::error::{JsError, Kind};
 use vm::{Context, JsResult, JsResultExt};
@@ -131,6 +133,46 @@ impl<'a, T: Value> Handle<'a, T> {
         }
     }
 
+    /// Classifies this value by its type, for matching on values that can be one of several
+    /// types. This is cheaper than attempting a series of downcasts.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult};
+    /// use neon::js::{JsValue, JsNumber, JsVariant, Value};
+    /// use neon::mem::Handle;
+    ///
+    /// fn length(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let v: Handle<JsValue> = cx.argument(0)?;
+    ///     let len = match v.variant() {
+    ///         JsVariant::String(s) => s.size() as f64,
+    ///         JsVariant::Array(a) => a.len() as f64,
+    ///         _ => 0.0
+    ///     };
+    ///     Ok(cx.number(len))
+    /// }
+    /// ```
+    pub fn variant(&self) -> JsVariant<'a> {
+        let raw = self.value.to_raw();
+        unsafe {
+            match neon_runtime::tag::of(raw) {
+                Tag::Undefined   => JsVariant::Undefined(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Null        => JsVariant::Null(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Boolean     => JsVariant::Boolean(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Number      => JsVariant::Number(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::String      => JsVariant::String(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Array       => JsVariant::Array(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Function    => JsVariant::Function(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Buffer      => JsVariant::Buffer(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::ArrayBuffer => JsVariant::ArrayBuffer(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Error       => JsVariant::Error(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Object      => JsVariant::Object(Handle::new_internal(Managed::from_raw(raw))),
+                Tag::Other       => JsVariant::Other(Handle::new_internal(Managed::from_raw(raw)))
+            }
+        }
+    }
+
 }
 
 impl<'a, T: Managed> Deref for Handle<'a, T> {
@@ -145,3 +187,35 @@ impl<'a, T: Managed> DerefMut for Handle<'a, T> {
         &mut self.value
     }
 }
+
+/// A handle that keeps a JS value alive across handle scopes, until the handle is dropped.
+/// Persistent handles belong to the isolate that created them, so they must only be stored in
+/// per-isolate data.
+pub(crate) struct Persistent {
+    handle: *mut c_void
+}
+
+impl Persistent {
+    pub(crate) fn new<'a, T: Managed>(value: Handle<'a, T>) -> Persistent {
+        Persistent {
+            handle: unsafe { neon_runtime::mem::new_persistent(value.to_raw()) }
+        }
+    }
+
+    pub(crate) fn get<'a, C: Context<'a>, T: Managed>(&self, _: &mut C) -> Handle<'a, T> {
+        Handle::new_internal(T::from_raw(unsafe { self.read() }))
+    }
+
+    // Reads the value into a new local handle in the current handle scope.
+    pub(crate) unsafe fn read(&self) -> raw::Local {
+        let mut local: raw::Local = ::std::mem::zeroed();
+        neon_runtime::mem::read_persistent(&mut local, self.handle);
+        local
+    }
+}
+
+impl Drop for Persistent {
+    fn drop(&mut self) {
+        unsafe { neon_runtime::mem::drop_persistent(self.handle) }
+    }
+}
@@ -0,0 +1,212 @@
+//! Serialization of JavaScript values to bytes, using the same structured clone format as the
+//! Node `v8.serialize` and `v8.deserialize` functions.
+//!
+//! Instances of classes created with `declare_types!` are written with the hooks of the
+//! `Serializable` trait, once the class has been registered with `Serializable::register`.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::panic::AssertUnwindSafe;
+use std::ptr;
+use std::slice;
+use neon_runtime;
+use neon_runtime::raw;
+use mem::{Handle, Managed};
+use vm::{Context, ExecuteContext, ComputeContext, VmResult, JsResult, Throw};
+use js::{Value, JsValue, build};
+use js::class::Class;
+use js::error::{JsError, Kind, convert_panics};
+
+/// The trait of classes whose instances can be serialized.
+///
+/// # Example:
+///
+/// ```rust,ignore
+/// impl Serializable for JsGreeter {
+///     fn serialize<'a, C: Context<'a>>(cx: &mut C, this: Handle<'a, Self>) -> VmResult<Vec<u8>> {
+///         let guard = cx.lock();
+///         let greeting = this.borrow(&guard).greeting.clone();
+///         Ok(greeting.into_bytes())
+///     }
+///
+///     fn deserialize<'a, C: Context<'a>>(cx: &mut C, data: &[u8]) -> JsResult<'a, Self> {
+///         let greeting = cx.string(String::from_utf8_lossy(data));
+///         JsGreeter::new(cx, vec![greeting])
+///     }
+/// }
+///
+/// JsGreeter::register(&mut cx, "Greeter");
+/// ```
+pub trait Serializable: Class {
+    /// Writes the state of an instance as bytes.
+    fn serialize<'a, C: Context<'a>>(cx: &mut C, this: Handle<'a, Self>) -> VmResult<Vec<u8>>;
+
+    /// Recreates an instance from the bytes written by `serialize`.
+    fn deserialize<'a, C: Context<'a>>(cx: &mut C, data: &[u8]) -> JsResult<'a, Self>;
+
+    /// Registers the class so its instances can be serialized in the current isolate. The `tag`
+    /// is written with each instance to identify its class when it's deserialized, so it must be
+    /// unique and stay the same for as long as the serialized data is kept.
+    fn register<'a, C: Context<'a>>(cx: &mut C, tag: &str) {
+        cx.isolate().host_registry().classes.insert(TypeId::of::<Self>(), HostClass {
+            tag: tag.to_string(),
+            is_instance: is_instance::<Self>,
+            serialize: serialize_instance::<Self>,
+            deserialize: deserialize_instance::<Self>
+        });
+    }
+}
+
+struct HostClass {
+    tag: String,
+    is_instance: fn(raw::Local) -> bool,
+    serialize: fn(&mut ExecuteContext, raw::Local) -> VmResult<Vec<u8>>,
+    deserialize: for<'b, 'c> fn(ComputeContext<'b, 'c>, &[u8]) -> JsResult<'b, JsValue>
+}
+
+// The serializable classes registered in an isolate, by type and by tag.
+pub(crate) struct HostRegistry {
+    classes: HashMap<TypeId, HostClass>
+}
+
+impl HostRegistry {
+    pub(crate) fn new() -> HostRegistry {
+        HostRegistry {
+            classes: HashMap::new()
+        }
+    }
+
+    fn find_instance(&self, object: raw::Local) -> Option<&HostClass> {
+        self.classes.values().find(|class| (class.is_instance)(object))
+    }
+
+    fn find_tag(&self, tag: &str) -> Option<&HostClass> {
+        self.classes.values().find(|class| class.tag == tag)
+    }
+}
+
+fn is_instance<T: Serializable>(object: raw::Local) -> bool {
+    let value: Handle<JsValue> = Handle::new_internal(JsValue::from_raw(object));
+    value.is_a::<T>()
+}
+
+fn serialize_instance<T: Serializable>(cx: &mut ExecuteContext, object: raw::Local) -> VmResult<Vec<u8>> {
+    T::serialize(cx, Handle::new_internal(T::from_raw(object)))
+}
+
+fn deserialize_instance<'b, 'c, T: Serializable>(mut cx: ComputeContext<'b, 'c>, data: &[u8]) -> JsResult<'b, JsValue> {
+    Ok(T::deserialize(&mut cx, data)?.upcast())
+}
+
+struct WriteState<'b, C: 'b> {
+    cx: &'b mut C,
+    buffer: Vec<u8>
+}
+
+extern "C" fn write_host<'a, C: Context<'a>>(host: *mut c_void, object: raw::Local, out_data: &mut *const u8, out_len: &mut usize) -> bool {
+    let state: &mut WriteState<C> = unsafe { &mut *(host as *mut WriteState<C>) };
+    let result = convert_panics(AssertUnwindSafe(|| {
+        let class = match state.cx.isolate().host_registry().find_instance(object) {
+            Some(class) => (class.tag.clone(), class.serialize),
+            None => {
+                return JsError::throw(state.cx, Kind::TypeError, "object could not be cloned: its class is not registered for serialization");
+            }
+        };
+        let (tag, serialize) = class;
+        let data = state.cx.execute_scoped(|mut cx| serialize(&mut cx, object))?;
+        // The tag is written before the data, prefixed with its length.
+        let mut buffer = Vec::with_capacity(4 + tag.len() + data.len());
+        buffer.extend_from_slice(&encode_len(tag.len() as u32));
+        buffer.extend_from_slice(tag.as_bytes());
+        buffer.extend_from_slice(&data);
+        if buffer.len() > (::std::u32::MAX as usize) {
+            return JsError::throw(state.cx, Kind::RangeError, "serialized object data is too large");
+        }
+        Ok(buffer)
+    }));
+    match result {
+        Ok(buffer) => {
+            state.buffer = buffer;
+            *out_data = state.buffer.as_ptr();
+            *out_len = state.buffer.len();
+            true
+        }
+        Err(_) => false
+    }
+}
+
+extern "C" fn read_host<'a, C: Context<'a>>(host: *mut c_void, data: *const u8, len: usize, out: &mut raw::Local) -> bool {
+    let cx: &mut C = unsafe { &mut *(host as *mut C) };
+    let data = unsafe { slice::from_raw_parts(data, len) };
+    let result = convert_panics(AssertUnwindSafe(|| {
+        let (tag, data) = match split_tag(data) {
+            Some(pair) => pair,
+            None => { return JsError::throw(cx, Kind::Error, "invalid host object data"); }
+        };
+        let deserialize = match cx.isolate().host_registry().find_tag(tag) {
+            Some(class) => class.deserialize,
+            None => {
+                let msg = format!("no class is registered for serialization with tag {:?}", tag);
+                return JsError::throw(cx, Kind::TypeError, &msg);
+            }
+        };
+        cx.compute_scoped(|cx| deserialize(cx, data))
+    }));
+    match result {
+        Ok(value) => {
+            *out = value.to_raw();
+            true
+        }
+        Err(_) => false
+    }
+}
+
+fn encode_len(len: u32) -> [u8; 4] {
+    [len as u8, (len >> 8) as u8, (len >> 16) as u8, (len >> 24) as u8]
+}
+
+fn split_tag(data: &[u8]) -> Option<(&str, &[u8])> {
+    if data.len() < 4 {
+        return None;
+    }
+    let len = data[..4].iter().rev().fold(0, |len, &byte| (len << 8) | byte as usize);
+    let rest = &data[4..];
+    if rest.len() < len {
+        return None;
+    }
+    let tag = ::std::str::from_utf8(&rest[..len]).ok()?;
+    Some((tag, &rest[len..]))
+}
+
+/// Serializes a value to bytes with the structured clone algorithm. Throws an `Error` if the
+/// value contains something that can't be cloned, such as a function, and a `TypeError` for an
+/// instance of a class that isn't registered with `Serializable::register`.
+pub fn serialize<'a, C: Context<'a>, V: Value>(cx: &mut C, value: Handle<V>) -> VmResult<Vec<u8>> {
+    let isolate = cx.isolate().to_raw();
+    let mut state = WriteState {
+        cx: cx,
+        buffer: Vec::new()
+    };
+    let host = &mut state as *mut WriteState<C> as *mut c_void;
+    let mut data: *mut u8 = ptr::null_mut();
+    let mut len: usize = 0;
+    if !unsafe { neon_runtime::serialize::write(&mut data, &mut len, isolate, value.to_raw(), host, write_host::<C>) } {
+        return Err(Throw);
+    }
+    unsafe {
+        let bytes = slice::from_raw_parts(data, len).to_vec();
+        neon_runtime::serialize::free_buffer(data);
+        Ok(bytes)
+    }
+}
+
+/// Deserializes a value from bytes written by `serialize` or by the Node `v8.serialize`
+/// function. Throws an `Error` if the data is invalid.
+pub fn deserialize<'a, C: Context<'a>>(cx: &mut C, data: &[u8]) -> JsResult<'a, JsValue> {
+    let isolate = cx.isolate().to_raw();
+    let host = cx as *mut C as *mut c_void;
+    build(|out| { unsafe {
+        neon_runtime::serialize::read(out, isolate, data.as_ptr(), data.len(), host, read_host::<C>)
+    } })
+}
@@ -1,13 +1,20 @@
 //! A trait for defining Rust _tasks_ to be executed in a background thread.
 
-use std::marker::{Send, Sized};
+use std::cell::{Cell, RefCell};
+use std::marker::{Send, Sized, PhantomData};
 use std::mem;
 use std::os::raw::c_void;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use js::{Value, JsFunction};
-use mem::Handle;
+use js::{Value, Object, JsValue, JsBoolean, JsFunction, JsObject, JsUndefined};
+use js::error::{self, JsError, Kind};
+use js::promise::JsPromise;
+use js::internal::boxed_callback;
+use mem::{Handle, Persistent};
 use mem::Managed;
-use vm::{TaskContext, JsResult};
+use vm::{Context, TaskContext, JsResult, JsResultExt, VmResult};
 use neon_runtime;
 use neon_runtime::raw;
 
@@ -46,6 +53,156 @@ pub trait Task: Send + Sized {
                                          callback_raw);
         }
     }
+
+    /// Schedule a task to be executed on a background thread, producing a handle that can
+    /// cancel it. A cancelled task that hasn't started yet is never performed; one that's
+    /// already running can stop early by checking `is_cancelled`. Either way, the callback
+    /// is called with an `AbortError`.
+    fn schedule_cancellable(self, callback: Handle<JsFunction>) -> CancelHandle {
+        schedule_cancellable(self, callback, Cancellation::new())
+    }
+
+    /// Schedule a task to be executed on a background thread, which is cancelled when the JS
+    /// [`AbortSignal`](https://developer.mozilla.org/docs/Web/API/AbortSignal) `signal` is
+    /// aborted, as with `schedule_cancellable`. If the signal is already aborted, the task is
+    /// never scheduled, and the callback is called with an `AbortError` after the current JS
+    /// code finishes. The task isn't scheduled if reading the signal or adding the abort
+    /// listener throws.
+    fn schedule_with_signal<'a, C: Context<'a>>(self, cx: &mut C, callback: Handle<JsFunction>, signal: Handle<JsObject>) -> VmResult<()> {
+        let aborted = signal.get(cx, "aborted")?;
+        if aborted.downcast::<JsBoolean>().map(|b| b.value()).unwrap_or(false) {
+            return call_aborted(cx, callback);
+        }
+
+        let cancellation = Cancellation::new();
+        let listener_handle = CancelHandle {
+            cancellation: cancellation.clone(),
+            phantom: PhantomData
+        };
+        let listener = JsFunction::from_closure(cx, boxed_callback(move |_| {
+            listener_handle.cancel();
+            Ok(JsUndefined::new_internal().upcast())
+        }))?;
+        let event = cx.string("abort");
+        let add_event_listener = signal.get(cx, "addEventListener")?.downcast::<JsFunction>().unwrap_or_throw(cx)?;
+        add_event_listener.call(cx, signal, vec![event.upcast::<JsValue>(), listener.upcast()])?;
+
+        // The listener is removed when the task completes, so a long-lived signal doesn't
+        // accumulate listeners.
+        *cancellation.listener.borrow_mut() = Some((Persistent::new(signal), Persistent::new(listener)));
+        let handle = schedule_cancellable(self, callback, cancellation);
+        // The listener may have already been called if the signal was aborted while it was
+        // being added, before there was a request to cancel.
+        if handle.is_cancelled() {
+            handle.cancel();
+        }
+        Ok(())
+    }
+}
+
+// Calls the callback of a task whose signal was already aborted from a microtask, so that it's
+// called asynchronously, as if the task had been scheduled and cancelled.
+fn call_aborted<'a, C: Context<'a>>(cx: &mut C, callback: Handle<JsFunction>) -> VmResult<()> {
+    let callback = Persistent::new(callback);
+    let undefined = cx.undefined();
+    let ready = JsPromise::resolve(cx, undefined)?;
+    ready.when_settled(cx, move |mut cx, _| {
+        let callback: Handle<JsFunction> = callback.get(&mut cx);
+        let err = abort_error(&mut cx)?;
+        let undefined = cx.undefined();
+        callback.call(&mut cx, undefined, vec![err])?;
+        Ok(cx.undefined())
+    })?;
+    Ok(())
+}
+
+fn schedule_cancellable<T: Task>(task: T, callback: Handle<JsFunction>, cancellation: Arc<Cancellation>) -> CancelHandle {
+    let scheduled = Box::new(Scheduled {
+        task: task,
+        cancellation: cancellation.clone()
+    });
+    unsafe {
+        let request = neon_runtime::task::schedule_cancellable(Box::into_raw(scheduled) as *mut c_void,
+                                                               perform_cancellable_task::<T>,
+                                                               complete_cancellable_task::<T>,
+                                                               callback.to_raw());
+        cancellation.request.set(request);
+    }
+    CancelHandle {
+        cancellation: cancellation,
+        phantom: PhantomData
+    }
+}
+
+/// Indicates whether the task being performed on the current thread has been cancelled.
+/// Long-running tasks scheduled with `Task::schedule_cancellable` or
+/// `Task::schedule_with_signal` can check this periodically in `perform` to stop early;
+/// the result they return is discarded. Always `false` outside of such a task.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| {
+        match *current.borrow() {
+            Some(ref cancellation) => cancellation.cancelled.load(Ordering::SeqCst),
+            None => false
+        }
+    })
+}
+
+thread_local! {
+    // The cancellation state of the task being performed on this thread.
+    static CURRENT: RefCell<Option<Arc<Cancellation>>> = RefCell::new(None);
+}
+
+struct Cancellation {
+    cancelled: AtomicBool,
+    // The runtime's handle to the task, until it completes. Only used on the main thread.
+    request: Cell<*mut c_void>,
+    // The signal and abort listener of a task scheduled with `schedule_with_signal`. Only used
+    // on the main thread.
+    listener: RefCell<Option<(Persistent, Persistent)>>
+}
+
+impl Cancellation {
+    fn new() -> Arc<Cancellation> {
+        Arc::new(Cancellation {
+            cancelled: AtomicBool::new(false),
+            request: Cell::new(ptr::null_mut()),
+            listener: RefCell::new(None)
+        })
+    }
+}
+
+// Only the `cancelled` flag is accessed from the background thread.
+unsafe impl Send for Cancellation { }
+unsafe impl Sync for Cancellation { }
+
+/// A handle for cancelling a task, produced by `Task::schedule_cancellable`.
+#[derive(Clone)]
+pub struct CancelHandle {
+    cancellation: Arc<Cancellation>,
+    // The handle must stay on the main thread.
+    phantom: PhantomData<*mut ()>
+}
+
+impl CancelHandle {
+    /// Cancels the task. The task's callback is called with an `AbortError`, unless it was
+    /// already called. Cancelling a task more than once has no further effect.
+    pub fn cancel(&self) {
+        self.cancellation.cancelled.store(true, Ordering::SeqCst);
+        let request = self.cancellation.request.get();
+        if !request.is_null() {
+            unsafe { neon_runtime::task::cancel(request); }
+        }
+    }
+
+    /// Indicates whether the task has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+struct Scheduled<T> {
+    task: T,
+    cancellation: Arc<Cancellation>
 }
 
 unsafe extern "C" fn perform_task<T: Task>(task: *mut c_void) -> *mut c_void {
@@ -64,3 +221,66 @@ unsafe extern "C" fn complete_task<T: Task>(task: *mut c_void, result: *mut c_vo
         }
     })
 }
+
+unsafe extern "C" fn perform_cancellable_task<T: Task>(task: *mut c_void) -> *mut c_void {
+    let scheduled: &Scheduled<T> = &*(task as *const Scheduled<T>);
+    // A task can be cancelled after a thread takes it from the queue, but before it starts.
+    if scheduled.cancellation.cancelled.load(Ordering::SeqCst) {
+        return ptr::null_mut();
+    }
+    CURRENT.with(|current| *current.borrow_mut() = Some(scheduled.cancellation.clone()));
+    let result = scheduled.task.perform();
+    CURRENT.with(|current| current.borrow_mut().take());
+    Box::into_raw(Box::new(result)) as *mut c_void
+}
+
+unsafe extern "C" fn complete_cancellable_task<T: Task>(task: *mut c_void, result: *mut c_void, out: &mut raw::Local) {
+    let scheduled: Box<Scheduled<T>> = Box::from_raw(task as *mut Scheduled<T>);
+    let Scheduled { task, cancellation } = *scheduled;
+    cancellation.request.set(ptr::null_mut());
+    // The result is null if the task was cancelled before it was performed.
+    let result: Option<Result<T::Output, T::Error>> = if result.is_null() {
+        None
+    } else {
+        Some(*Box::from_raw(result as *mut Result<T::Output, T::Error>))
+    };
+    TaskContext::with(|mut cx| {
+        if let Some((signal, listener)) = cancellation.listener.borrow_mut().take() {
+            let _ = remove_abort_listener(&mut cx, signal, listener);
+        }
+        match result {
+            Some(result) if !cancellation.cancelled.load(Ordering::SeqCst) => {
+                if let Ok(result) = task.complete(cx, result) {
+                    *out = result.to_raw();
+                }
+            }
+            _ => {
+                let _ = throw_abort_error(&mut cx);
+            }
+        }
+    })
+}
+
+fn remove_abort_listener<'a>(cx: &mut TaskContext<'a>, signal: Persistent, listener: Persistent) -> VmResult<()> {
+    let signal: Handle<JsObject> = signal.get(cx);
+    let listener: Handle<JsValue> = listener.get(cx);
+    let event = cx.string("abort");
+    let remove_event_listener = signal.get(cx, "removeEventListener")?.downcast::<JsFunction>().unwrap_or_throw(cx)?;
+    remove_event_listener.call(cx, signal, vec![event.upcast::<JsValue>(), listener])?;
+    Ok(())
+}
+
+// Throws an error like the ones Node produces for aborted operations.
+fn throw_abort_error<'a>(cx: &mut TaskContext<'a>) -> VmResult<()> {
+    let err = abort_error(cx)?;
+    error::throw(cx, err)
+}
+
+fn abort_error<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsError> {
+    let err = JsError::new(cx, Kind::Error, "The operation was aborted")?;
+    let name = cx.string("AbortError");
+    err.set(cx, "name", name)?;
+    let code = cx.string("ABORT_ERR");
+    err.set(cx, "code", code)?;
+    Ok(err)
+}
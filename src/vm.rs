@@ -1,11 +1,13 @@
 //! Abstractions representing the JavaScript virtual machine and its control flow.
 
 use std::cell::RefCell;
+use std::cmp;
 use std::mem;
 use std::any::TypeId;
 use std::convert::Into;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::future::Future;
 use std::marker::PhantomData;
 use std::collections::HashMap;
 use std::os::raw::c_void;
@@ -13,12 +15,16 @@ use std::panic::UnwindSafe;
 use neon_runtime;
 use neon_runtime::raw;
 use neon_runtime::call::CCallback;
-use js::{JsValue, Value, Object, JsObject, JsArray, JsFunction, JsBoolean, JsNumber, JsString, StringResult, JsNull, JsUndefined, Ref, RefMut, Borrow, BorrowMut};
+use js::{JsValue, Value, IntoJs, build, Object, JsObject, JsArray, JsFunction, JsBoolean, JsNumber, JsString, StringResult, JsNull, JsUndefined, Ref, RefMut, Borrow, BorrowMut};
 use js::binary::{JsArrayBuffer, JsBuffer};
 use js::class::internal::ClassMetadata;
 use js::class::Class;
+use js::internal::boxed_callback;
+use js::promise::JsPromise;
 use js::error::{JsError, Kind};
-use mem::{Handle, Managed};
+use js::property::intern_name;
+use js::iter;
+use mem::{Handle, Managed, Persistent};
 use self::internal::{Ledger, ContextInternal, Scope, ScopeMetadata};
 
 pub(crate) mod internal {
@@ -32,7 +38,8 @@ pub(crate) mod internal {
     use mem::Handle;
     use vm::VmResult;
     use js::{JsObject, LoanError};
-    use super::{ClassMap, ModuleContext};
+    use super::{ClassMap, KeyCache, ModuleContext};
+    use serialize::HostRegistry;
 
     pub unsafe trait Pointer {
         unsafe fn as_ptr(&self) -> *const c_void;
@@ -108,8 +115,15 @@ pub(crate) mod internal {
     #[derive(Clone, Copy)]
     pub struct Isolate(*mut raw::Isolate);
 
-    extern "C" fn drop_class_map(map: Box<ClassMap>) {
-        mem::drop(map);
+    // The data Neon associates with each isolate, which is dropped when the isolate exits.
+    pub(crate) struct IsolateData {
+        classes: ClassMap,
+        keys: KeyCache,
+        hosts: HostRegistry
+    }
+
+    extern "C" fn drop_isolate_data(data: Box<IsolateData>) {
+        mem::drop(data);
     }
 
     impl Isolate {
@@ -118,20 +132,40 @@ pub(crate) mod internal {
             ptr
         }
 
-        pub(crate) fn class_map(&mut self) -> &mut ClassMap {
+        fn data(&mut self) -> &mut IsolateData {
             let mut ptr: *mut c_void = unsafe { neon_runtime::class::get_class_map(self.to_raw()) };
             if ptr.is_null() {
-                let b: Box<ClassMap> = Box::new(ClassMap::new());
+                let b: Box<IsolateData> = Box::new(IsolateData {
+                    classes: ClassMap::new(),
+                    keys: KeyCache::new(),
+                    hosts: HostRegistry::new()
+                });
                 let raw = Box::into_raw(b);
                 ptr = unsafe { mem::transmute(raw) };
-                let free_map: *mut c_void = unsafe { mem::transmute(drop_class_map as usize) };
+                let free_data: *mut c_void = unsafe { mem::transmute(drop_isolate_data as usize) };
                 unsafe {
-                    neon_runtime::class::set_class_map(self.to_raw(), ptr, free_map);
+                    neon_runtime::class::set_class_map(self.to_raw(), ptr, free_data);
                 }
             }
             unsafe { mem::transmute(ptr) }
         }
 
+        pub(crate) fn class_map(&mut self) -> &mut ClassMap {
+            &mut self.data().classes
+        }
+
+        pub(crate) fn key_cache(&mut self) -> &mut KeyCache {
+            &mut self.data().keys
+        }
+
+        pub(crate) fn host_registry(&mut self) -> &mut HostRegistry {
+            &mut self.data().hosts
+        }
+
+        pub(crate) fn from_raw(ptr: *mut raw::Isolate) -> Isolate {
+            Isolate(ptr)
+        }
+
         pub(crate) fn current() -> Isolate {
             unsafe {
                 mem::transmute(neon_runtime::call::current_isolate())
@@ -196,6 +230,10 @@ pub(crate) mod internal {
 
     pub fn initialize_module(exports: Handle<JsObject>, init: fn(ModuleContext) -> VmResult<()>) {
         ModuleContext::with(exports, |cx| {
+            // Capture the built-in functions Neon calls before any more user code runs.
+            if !unsafe { neon_runtime::object::capture_builtins(cx.isolate().to_raw()) } {
+                return;
+            }
             let _ = init(cx);
         });
     }
@@ -249,6 +287,38 @@ impl ClassMap {
     }
 }
 
+// Property keys that are created once per isolate and kept alive for reuse.
+pub(crate) struct KeyCache {
+    names: HashMap<String, Persistent>,
+    shapes: HashMap<(usize, usize), Persistent>
+}
+
+impl KeyCache {
+    fn new() -> KeyCache {
+        KeyCache {
+            names: HashMap::new(),
+            shapes: HashMap::new()
+        }
+    }
+
+    pub fn get_name(&self, name: &str) -> Option<&Persistent> {
+        self.names.get(name)
+    }
+
+    pub fn set_name(&mut self, name: &str, val: Persistent) {
+        self.names.insert(name.to_string(), val);
+    }
+
+    // Shapes are identified by the address and length of their `'static` key slice.
+    pub fn get_shape(&self, keys: &'static [&'static str]) -> Option<&Persistent> {
+        self.shapes.get(&(keys.as_ptr() as usize, keys.len()))
+    }
+
+    pub fn set_shape(&mut self, keys: &'static [&'static str], val: Persistent) {
+        self.shapes.insert((keys.as_ptr() as usize, keys.len()), val);
+    }
+}
+
 #[repr(C)]
 pub(crate) struct CallbackInfo {
     info: raw::FunctionCallbackInfo
@@ -298,6 +368,18 @@ impl CallbackInfo {
         }
     }
 
+    /// Produces the name of the function being called, or `"<anonymous>"` if it has none.
+    pub fn callee_name<'b, C: Context<'b>>(&self, _: &mut C) -> String {
+        unsafe {
+            let mut name: raw::Local = mem::zeroed();
+            if neon_runtime::call::callee_name(&self.info, &mut name) {
+                JsString::from_raw(name).value()
+            } else {
+                "<anonymous>".to_string()
+            }
+        }
+    }
+
     pub fn require<'b, C: Context<'b>>(&self, cx: &mut C, i: i32) -> JsResult<'b, JsValue> {
         if i < 0 || i >= self.len() {
             return JsError::throw(cx, Kind::TypeError, "not enough arguments");
@@ -494,6 +576,22 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsString::try_new(self, s)
     }
 
+    /// Produces an internalized `JsString` for a property name. The string is created the first
+    /// time a name is interned in each isolate and reused after that, which makes it cheaper than
+    /// a `&str` key for properties that are accessed often.
+    ///
+    /// Interned strings stay alive as long as the isolate, so this is meant for a fixed set of
+    /// names rather than arbitrary data. See also [`PropertyName`](../js/property/struct.PropertyName.html).
+    ///
+    /// If the string exceeds the limits of the JS VM, this method panics.
+    fn intern(&mut self, name: &str) -> Handle<'a, JsString> {
+        let mut local: raw::Local = unsafe { mem::zeroed() };
+        if !unsafe { intern_name(&mut local, self.isolate(), name) } {
+            panic!("failed to intern property name {:?}", name);
+        }
+        Handle::new_internal(JsString::from_raw(local))
+    }
+
     /// Convenience method for creating a `JsNull` value.
     fn null(&mut self) -> Handle<'a, JsNull> {
         JsNull::new()
@@ -524,6 +622,52 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBuffer::new(self, size)
     }
 
+    /// Parses JSON text into a value, like the JS `JSON.parse` function. Throws a `SyntaxError` if
+    /// the text isn't valid JSON.
+    fn json_parse(&mut self, text: &str) -> JsResult<'a, JsValue> {
+        let text = self.try_string(text).unwrap_or_throw(self)?;
+        build(|out| { unsafe { neon_runtime::json::parse(out, text.to_raw()) } })
+    }
+
+    /// Converts a value to JSON text, like the JS `JSON.stringify` function, indenting nested
+    /// values by `indent` spaces (at most 10), or producing compact text if `indent` is 0. Throws
+    /// a `TypeError` if the value can't be represented as JSON, such as `undefined` or a function.
+    fn json_stringify<V: Value>(&mut self, value: Handle<V>, indent: usize) -> JsResult<'a, JsString> {
+        let gap = self.string(" ".repeat(cmp::min(indent, 10)));
+        build(|out| { unsafe { neon_runtime::json::stringify(out, value.to_raw(), gap.to_raw()) } })
+    }
+
+    /// Iterates over a JS iterable, such as an array, a `Map`, a `Set` or a generator, calling
+    /// `f` with each value it produces. The callback returns `Ok(true)` to continue and
+    /// `Ok(false)` to stop early. If the iteration stops before the end, because the callback
+    /// stopped it, threw or panicked, the iterator's `return` method is called so it can clean up.
+    ///
+    /// Throws a `TypeError` if the value isn't iterable, and propagates any exception thrown by
+    /// the iterator or the callback.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, JsResult, JsResultExt};
+    /// # use neon::js::{JsNumber, JsValue};
+    /// # use neon::mem::Handle;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let iterable: Handle<JsValue> = cx.argument(0)?;
+    ///     let mut total = 0.0;
+    ///     cx.iterate(iterable, |cx, item| {
+    ///         total += item.downcast::<JsNumber>().unwrap_or_throw(cx)?.value();
+    ///         Ok(true)
+    ///     })?;
+    ///     Ok(cx.number(total))
+    /// }
+    /// ```
+    fn iterate<V, F>(&mut self, iterable: Handle<V>, f: F) -> VmResult<()>
+        where V: Value,
+              F: FnMut(&mut Self, Handle<'a, JsValue>) -> VmResult<bool>
+    {
+        iter::iterate(self, iterable, f)
+    }
+
     /// Produces a handle to the JavaScript global object.
     fn global(&mut self) -> Handle<'a, JsObject> {
         JsObject::build(|out| {
@@ -556,7 +700,80 @@ impl<'a> ModuleContext<'a> {
 
     /// Convenience method for exporting a Neon function from a module.
     pub fn export_function<T: Value>(&mut self, key: &str, f: fn(FunctionContext) -> JsResult<T>) -> VmResult<()> {
-        let value = JsFunction::new(self, f)?.upcast::<JsValue>();
+        let value = JsFunction::new(self, f)?;
+        let name = self.string(key);
+        unsafe {
+            neon_runtime::fun::set_name(value.to_raw(), name.to_raw());
+        }
+        self.exports.set(self, key, value)?;
+        Ok(())
+    }
+
+    /// Convenience method for exporting an asynchronous Rust function from a module. The
+    /// function reads its arguments on the JS thread and returns a future, which is spawned onto
+    /// the `executor`. The exported JS function returns a promise, which is resolved with the
+    /// future's `Ok` value, or rejected with an `Error` whose message is its `Err` value.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::vm::{Context, FunctionContext, ModuleContext, VmResult};
+    /// # use neon::js::JsString;
+    /// # use std::future::Future;
+    /// # fn download(url: String) -> impl Future<Output = Result<String, String>> + Send { std::future::ready(Ok(url)) }
+    /// fn fetch(cx: &mut FunctionContext) -> VmResult<impl Future<Output = Result<String, String>> + Send> {
+    ///     let url = cx.argument::<JsString>(0)?.value();
+    ///     Ok(download(url))
+    /// }
+    ///
+    /// # fn init(mut cx: ModuleContext) -> VmResult<()> {
+    /// cx.export_async_function("fetch", fetch)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// With the 2018 edition, the future can be an `async` block, or the result of an `async fn`:
+    ///
+    /// ```edition2018,no_run
+    /// # use neon::vm::{Context, FunctionContext, ModuleContext, VmResult};
+    /// # use neon::js::JsString;
+    /// # use std::future::Future;
+    /// async fn shout(s: String) -> Result<String, String> {
+    ///     Ok(s.to_uppercase())
+    /// }
+    ///
+    /// fn greet(cx: &mut FunctionContext) -> VmResult<impl Future<Output = Result<String, String>> + Send> {
+    ///     let name = cx.argument::<JsString>(0)?.value();
+    ///     Ok(async move {
+    ///         let name = shout(name).await?;
+    ///         Ok(format!("hello, {}!", name))
+    ///     })
+    /// }
+    ///
+    /// # fn init(mut cx: ModuleContext) -> VmResult<()> {
+    /// cx.export_async_function("greet", greet)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_async_function<F, T, E>(&mut self, key: &str, f: fn(&mut FunctionContext) -> VmResult<F>) -> VmResult<()>
+        where F: Future<Output = Result<T, E>> + Send + 'static,
+              T: IntoJs + Send + 'static,
+              E: Display + Send + 'static
+    {
+        let value = JsFunction::from_closure(self, boxed_callback(move |mut cx| {
+            let future = f(&mut cx)?;
+            let promise = JsPromise::from_future(&mut cx, future, |mut cx, result| {
+                match result {
+                    Ok(value) => Ok(value.into_js(&mut cx)?.upcast::<JsValue>()),
+                    Err(err) => JsError::throw(&mut cx, Kind::Error, &err.to_string())
+                }
+            })?;
+            Ok(promise.upcast())
+        }))?;
+        let name = self.string(key);
+        unsafe {
+            neon_runtime::fun::set_name(value.to_raw(), name.to_raw());
+        }
         self.exports.set(self, key, value)?;
         Ok(())
     }
@@ -670,9 +887,19 @@ impl<'a, T: This> CallContext<'a, T> {
     }
 
     /// Produces the `i`th argument and casts it to the type `V`, or throws an exception if `i` is greater than or equal to `self.len()` or cannot be cast to `V`.
+    ///
+    /// A failed cast throws a `TypeError` describing the argument's position and actual type,
+    /// e.g. `argument 2 of parse(): expected string, got object (null)`.
     pub fn argument<V: Value>(&mut self, i: i32) -> JsResult<'a, V> {
         let a = self.info.require(self, i)?;
-        a.downcast().unwrap_or_throw(self)
+        match a.downcast() {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                let msg = format!("argument {} of {}(): expected {}, got {}",
+                                  i, self.info.callee_name(self), V::name(), a.describe_type());
+                JsError::throw(self, Kind::TypeError, &msg)
+            }
+        }
     }
 
     /// Produces a handle to the `this`-binding.
@@ -0,0 +1,45 @@
+// Compares copying a large array through Neon one element at a time against
+// the bulk numeric slice conversions, and records the results in
+// bench/results.json.
+
+var addon = require('../native');
+var assert = require('chai').assert;
+var fs = require('fs');
+var path = require('path');
+
+var LENGTH = 1000000;
+var ROUNDS = 5;
+
+function time(f, input) {
+  f(input);
+  var start = process.hrtime();
+  for (var i = 0; i < ROUNDS; i++) {
+    f(input);
+  }
+  var elapsed = process.hrtime(start);
+  return (elapsed[0] * 1e3 + elapsed[1] / 1e6) / ROUNDS;
+}
+
+var input = new Array(LENGTH);
+for (var i = 0; i < LENGTH; i++) {
+  input[i] = i / 2;
+}
+
+// Both copies must produce the same array for the comparison to be meaningful.
+assert.deepEqual(addon.copy_js_array_in_bulk(input), addon.copy_js_array_by_element(input));
+
+var byElement = time(addon.copy_js_array_by_element, input);
+var inBulk = time(addon.copy_js_array_in_bulk, input);
+var results = {
+  node: process.version,
+  length: LENGTH,
+  rounds: ROUNDS,
+  ms: { byElement: byElement, inBulk: inBulk },
+  speedup: byElement / inBulk
+};
+
+console.log('by element: ' + byElement.toFixed(2) + 'ms per ' + LENGTH + ' elements');
+console.log('in bulk: ' + inBulk.toFixed(2) + 'ms per ' + LENGTH + ' elements');
+console.log('speedup: ' + results.speedup.toFixed(1) + 'x');
+
+fs.writeFileSync(path.join(__dirname, 'results.json'), JSON.stringify(results, null, 2) + '\n');
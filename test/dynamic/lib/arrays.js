@@ -13,4 +13,65 @@ describe('JsArray', function() {
   it('return a JsArray with an string at index 0', function () {
     assert.deepEqual(["hello node"], addon.return_js_array_with_string());
   });
+
+  it('return a JsArray built from an iterator', function () {
+    assert.deepEqual(addon.return_js_array_from_iter(), ['a', 'b', 'c']);
+  });
+
+  it('return a JsArray built from slices', function () {
+    assert.deepEqual(addon.return_js_array_from_slice(), [[1.5, 2.5], ['x', 'y'], [true, false]]);
+  });
+
+  it('pushes to a JsArray', function () {
+    var a = [1];
+    assert.equal(addon.push_to_js_array(a, 'two'), 2);
+    assert.deepEqual(a, [1, 'two']);
+    assert.throws(function() { addon.push_to_js_array(Object.freeze([1]), 'two') }, TypeError, /^cannot add element 1 to array$/);
+  });
+
+  it('gets typed elements of a JsArray', function () {
+    assert.equal(addon.sum_js_array([1, 2, 3.5]), 6.5);
+    assert.throws(function() { addon.sum_js_array([1, '2']) }, TypeError, /^element 1: expected number, got string$/);
+  });
+
+  it('iterates over a JsArray', function () {
+    assert.equal(addon.join_js_array(['a', 1, true]), 'a,1,true');
+    assert.equal(addon.join_js_array([]), '');
+  });
+
+  it('re-checks the length while iterating over a JsArray', function () {
+    var a = [1, 2, 3, 4];
+    Object.defineProperty(a, 1, { get: function() { a.length = 2; return 'two'; } });
+    assert.equal(addon.join_js_array(a), '1,two');
+  });
+
+  it('converts numeric slices to JsArrays', function () {
+    assert.deepEqual(addon.return_js_arrays_from_numeric_slices(),
+                     [[0.5, -1, 1e300], [-2147483648, -1, 0, 2147483647], [0, 1, 4294967295]]);
+  });
+
+  it('converts JsArrays to vectors of f64', function () {
+    assert.deepEqual(addon.double_f64_js_array([1, 2.5, -3]), [2, 5, -6]);
+    assert.deepEqual(addon.double_f64_js_array([]), []);
+    assert.throws(function() { addon.double_f64_js_array([1, '2']) }, TypeError, /^element 1: expected number, got string$/);
+  });
+
+  it('converts JsArrays to vectors of i32', function () {
+    assert.deepEqual(addon.negate_i32_js_array([1, -2, 0]), [-1, 2, 0]);
+    assert.throws(function() { addon.negate_i32_js_array([1, 1.5]) }, TypeError, /^element 1: expected 32-bit integer, got number$/);
+    assert.throws(function() { addon.negate_i32_js_array([2147483648]) }, TypeError, /^element 0: expected 32-bit integer, got number$/);
+  });
+
+  it('converts JsArrays to vectors of u32', function () {
+    assert.equal(addon.sum_u32_js_array([1, 2, 4294967295]), 4294967298);
+    assert.throws(function() { addon.sum_u32_js_array([-1]) }, TypeError, /^element 0: expected 32-bit unsigned integer, got number$/);
+  });
+
+  it('copies JsArrays element by element and in bulk', function () {
+    var a = [1, 2.5, -3];
+    assert.deepEqual(addon.copy_js_array_by_element(a), a);
+    assert.deepEqual(addon.copy_js_array_in_bulk(a), a);
+    assert.throws(function() { addon.copy_js_array_by_element([1, null]) }, TypeError, /^element 1: expected number, got object \(null\)$/);
+    assert.throws(function() { addon.copy_js_array_in_bulk([1, null]) }, TypeError, /^element 1: expected number, got object \(null\)$/);
+  });
 });
@@ -0,0 +1,34 @@
+var addon = require('../native');
+var assert = require('chai').assert;
+
+describe('JsBox', function() {
+  it('passes boxed Rust data back to Rust', function () {
+    var person = addon.box_person('Ferris');
+    assert.equal(addon.person_name(person), 'Ferris');
+  });
+
+  it('is opaque to JavaScript', function () {
+    var person = addon.box_person('Ferris');
+    assert.typeOf(person, 'object');
+    assert.deepEqual(Object.getOwnPropertyNames(person), []);
+    assert.deepEqual(Object.getOwnPropertySymbols(person), []);
+  });
+
+  it('allows interior mutability of boxed data', function () {
+    var counter = addon.box_counter();
+    assert.equal(addon.increment_counter(counter), 1);
+    assert.equal(addon.increment_counter(counter), 2);
+  });
+
+  it('downcasts only to the boxed type', function () {
+    assert.isTrue(addon.is_boxed_person(addon.box_person('Ferris')));
+    assert.isFalse(addon.is_boxed_person(addon.box_counter()));
+    assert.isFalse(addon.is_boxed_person({}));
+    assert.isFalse(addon.is_boxed_person(null));
+  });
+
+  it('throws a TypeError when given the wrong type', function () {
+    assert.throws(function() { addon.require_boxed_person(addon.box_counter()) }, TypeError);
+    assert.throws(function() { addon.require_boxed_person({ name: 'Ferris' }) }, TypeError);
+  });
+});
@@ -23,6 +23,15 @@ describe('JsClass', function() {
     assert.throw(function() { u.get('not_a_field') }, TypeError);
   });
 
+  it('names the class in constructor argument errors', function() {
+    assert.throws(function() { new User("1", "some", "thing", "else") }, TypeError, /^argument 0 of User\(\): expected number, got string$/);
+  });
+
+  it('names the class and method in method argument errors', function() {
+    var u = new User(1, "some", "thing", "else");
+    assert.throws(function() { u.get(17) }, TypeError, /^argument 0 of User\.get\(\): expected string, got number$/);
+  });
+
   it('converts a Rust panic to a throw in a method', function() {
     var u = new User(1, "some", "thing", "else");
     assert.throws(function() { u.panic() }, Error, /^internal error in native module: User.prototype.panic$/);
@@ -39,4 +48,14 @@ describe('JsClass', function() {
   it('converts a Rust panic to a throw in a constructor allocator', function() {
     assert.throws(function() { new PanickyAllocator() }, Error, /^internal error in native module: allocator panicking$/);
   });
+
+  (typeof require('v8').serialize === 'function' ? it : it.skip)('serializes instances of registered classes', function() {
+    var u = new User(1, "some", "thing", "else");
+    var copy = addon.deserialize_value(addon.serialize_value({ user: u, users: [u, u] }));
+    assert(copy.user instanceof User);
+    assert.notStrictEqual(copy.user, u);
+    assert.strictEqual(copy.users[0], copy.user);
+    assert.equal(copy.user.get('id'), 1);
+    assert.equal(copy.user.get('email'), "else");
+  });
 });
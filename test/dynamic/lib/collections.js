@@ -0,0 +1,170 @@
+var addon = require('../native');
+var assert = require('chai').assert;
+
+describe('JsMap', function() {
+  it('return a JsMap keyed by an object built in Rust', function () {
+    var key = {};
+    var map = addon.return_js_map_with_object_key(key);
+    assert.instanceOf(map, Map);
+    assert.equal(map.size, 2);
+    assert.equal(map.get(key), 9000);
+    assert.equal(map.get(1), 'one');
+  });
+
+  it('reads a Map entry keyed by a non-string value', function () {
+    var key = { id: 1 };
+    var map = new Map([[key, 'object'], [1, 'number'], ['1', 'string']]);
+    assert.equal(addon.read_js_map_with_object_key(map, key), 'object');
+    assert.equal(addon.read_js_map_with_object_key(map, 1), 'number');
+    assert.equal(addon.read_js_map_with_object_key(map, '1'), 'string');
+    assert.isUndefined(addon.read_js_map_with_object_key(map, {}));
+  });
+
+  it('deletes a Map entry', function () {
+    var key = [];
+    var map = new Map([[key, true]]);
+    assert.isTrue(addon.delete_from_js_map(map, key));
+    assert.equal(map.size, 0);
+    assert.isFalse(addon.delete_from_js_map(map, key));
+  });
+
+  it('reads the entries of a Map in insertion order', function () {
+    var key = {};
+    var map = new Map([['b', 2], [key, 1], [NaN, 3]]);
+    assert.deepEqual(addon.js_map_entries(map), [['b', 2], [key, 1], [NaN, 3]]);
+  });
+
+  it('clears a Map', function () {
+    assert.equal(addon.clear_js_map(new Map([[1, 2], [3, 4]])), 0);
+  });
+
+  it('does not mistake a Map for a Set', function () {
+    assert.deepEqual(addon.is_js_map_or_set(new Map()), [true, false]);
+    assert.deepEqual(addon.is_js_map_or_set({}), [false, false]);
+  });
+});
+
+describe('JsSet', function() {
+  it('return a JsSet built in Rust', function () {
+    var set = addon.return_js_set();
+    assert.instanceOf(set, Set);
+    assert.deepEqual(Array.from(set), [1, 2, 3]);
+  });
+
+  it('reads the values of a Set in insertion order', function () {
+    var o = {};
+    assert.deepEqual(addon.js_set_values(new Set(['z', o, 3])), ['z', o, 3]);
+  });
+
+  it('deletes a Set element', function () {
+    var o = {};
+    var set = new Set([o, 'o']);
+    assert.equal(addon.delete_from_js_set(set, o), 1);
+    assert.isFalse(set.has(o));
+    assert.equal(addon.delete_from_js_set(set, {}), 1);
+  });
+
+  it('does not mistake a Set for a Map', function () {
+    assert.deepEqual(addon.is_js_map_or_set(new Set()), [false, true]);
+  });
+});
+
+describe('iteration', function() {
+  function counter(log, n) {
+    var i = 0;
+    var iterator = {
+      next: function() {
+        i++;
+        return i > n ? { done: true } : { done: false, value: i };
+      },
+      return: function() {
+        log.push('return');
+        return {};
+      }
+    };
+    iterator[Symbol.iterator] = function() { return iterator; };
+    return iterator;
+  }
+
+  it('collects the values of iterables', function () {
+    assert.deepEqual(addon.collect_iterable([1, 'two', 3]), [1, 'two', 3]);
+    assert.deepEqual(addon.collect_iterable(new Set(['a', 'b'])), ['a', 'b']);
+    assert.deepEqual(addon.collect_iterable(new Map([[1, 2]])), [[1, 2]]);
+    assert.deepEqual(addon.collect_iterable('héllo'), ['h', 'é', 'l', 'l', 'o']);
+  });
+
+  it('drives generators', function () {
+    function* gen() { yield 1; yield 2; yield 3; }
+    assert.deepEqual(addon.collect_iterable(gen()), [1, 2, 3]);
+    assert.equal(addon.sum_iterable(gen()), 6);
+  });
+
+  it('closes the iterator when stopping early', function () {
+    var log = [];
+    assert.deepEqual(addon.collect_iterable(counter(log, 5), 2), [1, 2]);
+    assert.deepEqual(log, ['return']);
+    log = [];
+    assert.deepEqual(addon.collect_iterable(counter(log, 2)), [1, 2]);
+    assert.deepEqual(log, []);
+
+    var finished = false;
+    function* gen() { try { yield 1; yield 2; } finally { finished = true; } }
+    assert.deepEqual(addon.collect_iterable(gen(), 1), [1]);
+    assert.isTrue(finished);
+  });
+
+  it('closes the iterator and propagates exceptions from Rust', function () {
+    var log = [];
+    var iterator = counter(log, 3);
+    var next = iterator.next;
+    iterator.next = function() {
+      var result = next();
+      if (result.value === 2) {
+        result.value = 'two';
+      }
+      return result;
+    };
+    assert.throws(function() { addon.sum_iterable(iterator) }, TypeError);
+    assert.deepEqual(log, ['return']);
+  });
+
+  it('propagates exceptions from the iterator', function () {
+    function* gen() { yield 1; throw new Error('oops'); }
+    assert.throws(function() { addon.sum_iterable(gen()) }, Error, /oops/);
+  });
+
+  it('throws for values that are not iterable', function () {
+    assert.throws(function() { addon.sum_iterable({}) }, TypeError, /not iterable/);
+    assert.throws(function() { addon.sum_iterable(1) }, TypeError, /not iterable/);
+    assert.throws(function() { addon.sum_iterable(null) }, TypeError);
+  });
+
+  it('exposes Rust iterators as JS iterators', function () {
+    assert.deepEqual(Array.from(addon.return_rust_iterator(3)), ['row 0', 'row 1', 'row 2']);
+    assert.deepEqual([...addon.return_rust_iterator(0)], []);
+
+    var iterator = addon.return_rust_iterator(1);
+    assert.strictEqual(iterator[Symbol.iterator](), iterator);
+    assert.deepEqual(iterator.next(), { value: 'row 0', done: false });
+    assert.deepEqual(iterator.next(), { value: undefined, done: true });
+    assert.deepEqual(iterator.next(), { value: undefined, done: true });
+  });
+
+  it('stops Rust iterators early', function () {
+    var squares = [];
+    for (var n of addon.return_infinite_rust_iterator()) {
+      if (n > 20) break;
+      squares.push(n);
+    }
+    assert.deepEqual(squares, [1, 4, 9, 16]);
+
+    var iterator = addon.return_infinite_rust_iterator();
+    assert.deepEqual(iterator.next(), { value: 1, done: false });
+    assert.deepEqual(iterator.return(42), { value: 42, done: true });
+    assert.deepEqual(iterator.next(), { value: undefined, done: true });
+  });
+
+  it('round-trips Rust iterators through iteration from Rust', function () {
+    assert.deepEqual(addon.collect_iterable(addon.return_infinite_rust_iterator(), 3), [1, 4, 9]);
+  });
+});
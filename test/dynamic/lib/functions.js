@@ -77,6 +77,21 @@ describe('JsFunction', function() {
     assert.throws(function() { addon.require_argument_zero_string(17) }, TypeError);
   });
 
+  it('describes the argument and its type when a cast fails', function() {
+    assert.throws(function() { addon.check_string_and_number('a', null) },
+                  TypeError, /^argument 1 of check_string_and_number\(\): expected number, got object \(null\)$/);
+    assert.throws(function() { addon.check_string_and_number([], 1) },
+                  TypeError, /^argument 0 of check_string_and_number\(\): expected string, got object \(Array\)$/);
+    assert.throws(function() { addon.check_string_and_number(undefined, 1) },
+                  TypeError, /^argument 0 of check_string_and_number\(\): expected string, got undefined$/);
+    assert.throws(function() { addon.return_js_function()('1') },
+                  TypeError, /^argument 0 of <anonymous>\(\): expected number, got string$/);
+  });
+
+  it('names exported functions after their keys', function() {
+    assert.equal(addon.check_string_and_number.name, 'check_string_and_number');
+  });
+
   it('executes a scoped computation', function() {
     assert.equal(addon.execute_scoped(), 99);
   });
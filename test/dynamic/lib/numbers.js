@@ -48,4 +48,39 @@ describe('JsNumber', function() {
     });
   });
 
+  it('return JsNumbers built from integers in Rust', function () {
+    assert.deepEqual(addon.return_js_integers(), [-2147483648, 4294967295]);
+  });
+
+  it('classifies integers', function () {
+    assert.deepEqual(addon.classify_js_number(-1), [true, false, true]);
+    assert.deepEqual(addon.classify_js_number(4294967295), [false, true, true]);
+    assert.deepEqual(addon.classify_js_number(9007199254740991), [false, false, true]);
+    assert.deepEqual(addon.classify_js_number(9007199254740992), [false, false, false]);
+    assert.deepEqual(addon.classify_js_number(1.5), [false, false, false]);
+    assert.deepEqual(addon.classify_js_number(NaN), [false, false, false]);
+    assert.deepEqual(addon.classify_js_number(Infinity), [false, false, false]);
+  });
+
+  it('converts integers to Rust integer types', function () {
+    assert.equal(addon.convert_js_number(-2147483648, 'i32'), '-2147483648');
+    assert.equal(addon.convert_js_number(4294967295, 'u32'), '4294967295');
+    assert.equal(addon.convert_js_number(-9007199254740991, 'i64'), '-9007199254740991');
+    assert.equal(addon.convert_js_number(Math.pow(2, 63), 'u64'), '9223372036854775808');
+    assert.equal(addon.convert_js_number(-0, 'usize'), '0');
+  });
+
+  it('throws a RangeError when converting out of range integers', function () {
+    assert.throws(function() { addon.convert_js_number(2147483648, 'i32') }, RangeError, /out of range for i32/);
+    assert.throws(function() { addon.convert_js_number(-1, 'u32') }, RangeError, /out of range for u32/);
+    assert.throws(function() { addon.convert_js_number(Math.pow(2, 63), 'i64') }, RangeError, /out of range for i64/);
+    assert.throws(function() { addon.convert_js_number(Math.pow(2, 64), 'u64') }, RangeError, /out of range for u64/);
+    assert.throws(function() { addon.convert_js_number(-1, 'usize') }, RangeError, /out of range for usize/);
+  });
+
+  it('throws a TypeError when converting non-integers', function () {
+    assert.throws(function() { addon.convert_js_number(1.5, 'i32') }, TypeError, /expected an integer/);
+    assert.throws(function() { addon.convert_js_number(NaN, 'u32') }, TypeError, /expected an integer/);
+    assert.throws(function() { addon.convert_js_number(Infinity, 'i64') }, TypeError, /expected an integer/);
+  });
 });
@@ -130,4 +130,230 @@ describe('JsObject', function() {
     addon.write_buffer_with_borrow_mut(b, 3, 66012);
     assert.equal(b.readUInt32LE(12), 66012);
   });
+
+  it('tests for own and inherited properties', function() {
+    var proto = { inherited: 1 };
+    var o = Object.create(proto);
+    o.own = 2;
+    assert.deepEqual(addon.has_properties(o, 'own'), [true, true, false]);
+    assert.deepEqual(addon.has_properties(o, 'inherited'), [true, false, false]);
+    assert.deepEqual(addon.has_properties(o, 'missing'), [false, false, false]);
+    assert.deepEqual(addon.has_properties(['a'], 'length'), [true, true, true]);
+    var sym = Symbol('key');
+    o[sym] = 3;
+    assert.deepEqual(addon.has_properties(o, sym), [true, true, false]);
+  });
+
+  it('deletes properties', function() {
+    var o = { a: 1 };
+    assert.isTrue(addon.delete_property(o, 'a'));
+    assert.notProperty(o, 'a');
+    Object.defineProperty(o, 'fixed', { value: 1, configurable: false });
+    assert.isFalse(addon.delete_property(o, 'fixed'));
+    assert.equal(o.fixed, 1);
+  });
+
+  it('defines read-only properties', function() {
+    var o = {};
+    assert.isTrue(addon.define_constant(o, 'PI', 3.14));
+    assert.deepEqual(Object.getOwnPropertyDescriptor(o, 'PI'),
+                     { value: 3.14, writable: false, enumerable: true, configurable: false });
+    o.PI = 3;
+    assert.equal(o.PI, 3.14);
+    assert.isFalse(addon.define_constant(o, 'PI', 3));
+  });
+
+  it('defines accessor properties', function() {
+    var o = { x: 1 };
+    assert.isTrue(addon.define_hidden_accessor(o, 'double', function() { return this.x * 2; },
+                                               function(v) { this.x = v / 2; }));
+    assert.equal(o.double, 2);
+    o.double = 10;
+    assert.equal(o.x, 5);
+    assert.deepEqual(Object.keys(o), ['x']);
+    assert.isTrue(addon.define_hidden_accessor(o, 'getOnly', function() { return 'got'; }));
+    assert.equal(o.getOnly, 'got');
+    assert.isUndefined(Object.getOwnPropertyDescriptor(o, 'getOnly').set);
+  });
+
+  it('describes own properties', function() {
+    var getter = function() { return 1; };
+    var o = { a: 'x' };
+    Object.defineProperty(o, 'b', { get: getter, enumerable: false, configurable: true });
+    assert.deepEqual(addon.describe_property(o, 'a'), Object.getOwnPropertyDescriptor(o, 'a'));
+    assert.deepEqual(addon.describe_property(o, 'b'), Object.getOwnPropertyDescriptor(o, 'b'));
+    assert.isUndefined(addon.describe_property(o, 'c'));
+    assert.isUndefined(addon.describe_property(Object.create(o), 'a'));
+  });
+
+  it('creates objects with a prototype', function() {
+    var proto = { greet: function() { return 'hi ' + this.name; } };
+    var o = addon.create_with_prototype(proto);
+    o.name = 'neon';
+    assert.strictEqual(Object.getPrototypeOf(o), proto);
+    assert.equal(o.greet(), 'hi neon');
+    assert.isNull(Object.getPrototypeOf(addon.create_with_prototype(null)));
+    assert.throws(function() { addon.create_with_prototype(17) }, TypeError);
+  });
+
+  it('gets and sets prototypes', function() {
+    var proto = {};
+    var o = {};
+    assert.strictEqual(addon.get_prototype(o), Object.prototype);
+    assert.isTrue(addon.set_prototype(o, proto));
+    assert.strictEqual(addon.get_prototype(o), proto);
+    assert.isTrue(addon.set_prototype(o, null));
+    assert.isNull(addon.get_prototype(o));
+    assert.isFalse(addon.set_prototype(Object.preventExtensions({}), proto));
+    assert.throws(function() { addon.set_prototype(o, 17) }, /^Object prototype may only be an Object or null$/);
+  });
+
+  it('freezes, seals and prevents extensions of objects', function() {
+    var frozen = { a: 1 };
+    assert.isTrue(addon.restrict_object(frozen, 'freeze'));
+    assert.deepEqual(addon.integrity_of(frozen), [true, true, false]);
+    assert.isTrue(Object.isFrozen(frozen));
+
+    var sealed = { a: 1 };
+    assert.isTrue(addon.restrict_object(sealed, 'seal'));
+    assert.deepEqual(addon.integrity_of(sealed), [false, true, false]);
+    sealed.a = 2;
+    assert.equal(sealed.a, 2);
+
+    var fixed = { a: 1 };
+    assert.isTrue(addon.restrict_object(fixed, 'prevent_extensions'));
+    assert.deepEqual(addon.integrity_of(fixed), [false, false, false]);
+    delete fixed.a;
+    assert.notProperty(fixed, 'a');
+
+    assert.deepEqual(addon.integrity_of({ a: 1 }), [false, false, true]);
+  });
+
+  it('reports when an object refuses to prevent extensions', function() {
+    var stubborn = new Proxy({}, { preventExtensions: function() { return false; } });
+    assert.isFalse(addon.restrict_object(stubborn, 'prevent_extensions'));
+    assert.deepEqual(addon.integrity_of(stubborn), [false, false, true]);
+  });
+
+  it('ignores replaced integrity functions', function() {
+    var originals = {
+      isFrozen: Object.isFrozen,
+      isSealed: Object.isSealed,
+      isExtensible: Object.isExtensible,
+      preventExtensions: Reflect.preventExtensions
+    };
+    try {
+      Object.isFrozen = 17;
+      delete Object.isSealed;
+      Object.isExtensible = function() { throw new Error('replaced'); };
+      Reflect.preventExtensions = null;
+      var fixed = { a: 1 };
+      assert.isTrue(addon.restrict_object(fixed, 'prevent_extensions'));
+      assert.deepEqual(addon.integrity_of(fixed), [false, false, false]);
+    } finally {
+      ['isFrozen', 'isSealed', 'isExtensible'].forEach(function(name) {
+        Object.defineProperty(Object, name, { value: originals[name], writable: true, enumerable: false, configurable: true });
+      });
+      Object.defineProperty(Reflect, 'preventExtensions', { value: originals.preventExtensions, writable: true, enumerable: false, configurable: true });
+    }
+  });
+
+  describe('property enumeration', function() {
+    var sym = Symbol('sym');
+    var proto = { inherited: 'i' };
+    var o = Object.create(proto);
+    o[1] = 'one';
+    o.visible = 'v';
+    o[sym] = 's';
+    Object.defineProperty(o, 'hidden', { value: 'h', enumerable: false, configurable: true });
+    Object.defineProperty(o, 'fixed', { value: 'f', enumerable: true, configurable: false });
+
+    function keys(entries) {
+      return entries.map(function(entry) { return entry[0]; });
+    }
+
+    it('lists own enumerable properties by default', function() {
+      var entries = addon.list_properties(o, {});
+      assert.deepEqual(keys(entries), Object.keys(o));
+      assert.strictEqual(keys(entries)[0], '1');
+      assert.deepEqual(entries.map(function(entry) { return entry[1]; }), ['one', 'v', 'f']);
+    });
+
+    it('includes inherited properties', function() {
+      assert.include(keys(addon.list_properties(o, { own_only: false })), 'inherited');
+    });
+
+    it('includes non-enumerable properties', function() {
+      assert.include(keys(addon.list_properties(o, { enumerable_only: false })), 'hidden');
+    });
+
+    it('skips non-configurable properties', function() {
+      assert.notInclude(keys(addon.list_properties(o, { configurable_only: true })), 'fixed');
+    });
+
+    it('includes symbols', function() {
+      var entries = addon.list_properties(o, { include_symbols: true });
+      assert.include(keys(entries), sym);
+      assert.notInclude(keys(addon.list_properties(o, {})), sym);
+    });
+
+    it('skips index keys', function() {
+      assert.deepEqual(keys(addon.list_properties(o, { include_indices: false })), ['visible', 'fixed']);
+    });
+
+    it('propagates exceptions thrown by getters', function() {
+      var throwing = { get boom() { throw new RangeError('boom'); } };
+      assert.throws(function() { addon.list_properties(throwing, {}) }, RangeError, /boom/);
+    });
+  });
+
+  it('defines accessors with Rust getters and setters', function() {
+    var o = addon.return_object_with_accessors();
+    assert.equal(o.count, 1);
+    assert.equal(o.count, 2);
+    o.count = 10;
+    assert.equal(o.count, 11);
+    assert.throws(function() { o.count = 'ten' }, TypeError, /expected number/);
+    assert.equal(o.answer, 42);
+    o.answer = 0;
+    assert.equal(o.answer, 42);
+    assert.strictEqual(o.self, o);
+    assert.deepEqual(Object.keys(o), ['count', 'answer', 'self']);
+    var descriptor = Object.getOwnPropertyDescriptor(o, 'answer');
+    assert.isFunction(descriptor.get);
+    assert.isUndefined(descriptor.set);
+  });
+
+  it('constructs objects from entries', function() {
+    var o = addon.return_object_from_entries();
+    assert.deepEqual(o, { number: 9000, string: 'hello node', flag: true });
+    assert.deepEqual(Object.keys(o), ['number', 'string', 'flag']);
+  });
+
+  it('constructs objects with a shape', function() {
+    assert.deepEqual(addon.return_records_with_shape(3), [
+      { id: 0, name: 'record 0', even: true },
+      { id: 1, name: 'record 1', even: false },
+      { id: 2, name: 'record 2', even: true }
+    ]);
+    // The second call reuses the keys cached by the first.
+    assert.deepEqual(addon.return_records_with_shape(1), [{ id: 0, name: 'record 0', even: true }]);
+    assert.deepEqual(addon.return_records_with_shape(0), []);
+  });
+
+  it('checks the number of values for a shape', function() {
+    assert.throws(function() { addon.build_shape_with_missing_value() }, RangeError, /^expected 2 values for object shape, got 1$/);
+  });
+
+  it('reads and writes properties with interned names', function() {
+    var o = { count: 1 };
+    assert.equal(addon.increment_count(o), 2);
+    assert.equal(addon.increment_count(o), 3);
+    assert.deepEqual(o, { count: 3 });
+    assert.isNaN(addon.increment_count({}));
+  });
+
+  it('interns property names', function() {
+    assert.deepEqual(addon.return_interned_names(), ['count', 'count', 'other']);
+  });
 });
@@ -0,0 +1,106 @@
+var addon = require('../native');
+var assert = require('chai').assert;
+
+describe('JsPromise', function() {
+  it('recognizes promises', function () {
+    assert.isTrue(addon.is_promise(Promise.resolve(1)));
+    assert.isFalse(addon.is_promise({ then: function() {} }));
+    assert.isFalse(addon.is_promise(1));
+  });
+
+  it('awaits a fulfilled promise', function () {
+    return addon.await_promise(Promise.resolve(42)).then(function(result) {
+      assert.deepEqual(result, { status: 'fulfilled', value: 42 });
+    });
+  });
+
+  it('awaits a rejected promise', function () {
+    var error = new Error('hook failed');
+    return addon.await_promise(Promise.reject(error)).then(function(result) {
+      assert.deepEqual(result, { status: 'rejected', value: error });
+    });
+  });
+
+  it('awaits a pending promise', function () {
+    var resolve;
+    var promise = new Promise(function(r) { resolve = r; });
+    var awaited = addon.await_promise(promise);
+    resolve('later');
+    return awaited.then(function(result) {
+      assert.deepEqual(result, { status: 'fulfilled', value: 'later' });
+    });
+  });
+
+  it('awaits thenables and plain values', function () {
+    var thenable = { then: function(resolve) { resolve('thenable'); } };
+    return Promise.all([addon.await_promise(thenable), addon.await_promise(7)]).then(function(results) {
+      assert.deepEqual(results, [
+        { status: 'fulfilled', value: 'thenable' },
+        { status: 'fulfilled', value: 7 }
+      ]);
+    });
+  });
+
+  it('completes a Rust future when the promise settles', function () {
+    var resolve;
+    addon.promise_to_future(new Promise(function(r) { resolve = r; }));
+    assert.isUndefined(addon.poll_promise_future());
+    resolve(3);
+    return Promise.resolve().then(function() {}).then(function() {
+      assert.equal(addon.poll_promise_future(), 3);
+    });
+  });
+
+  it('completes a Rust future when the promise is rejected', function () {
+    addon.promise_to_future(Promise.reject('nope'));
+    return Promise.resolve().then(function() {}).then(function() {
+      assert.throws(function() { addon.poll_promise_future() }, /nope/);
+    });
+  });
+});
+
+describe('async functions', function() {
+  it('resolves a promise when a Rust future completes', function () {
+    var promise = addon.delay_number(42);
+    assert.instanceOf(promise, Promise);
+    return promise.then(function(n) {
+      assert.equal(n, 42);
+    });
+  });
+
+  it('rejects a promise when a Rust future fails', function () {
+    return addon.delay_number(-1).then(function() {
+      assert.fail('promise should have been rejected');
+    }, function(err) {
+      assert.instanceOf(err, Error);
+      assert.equal(err.message, 'negative number: -1');
+    });
+  });
+
+  it('throws synchronously when reading arguments fails', function () {
+    assert.throws(function() { addon.delay_number('one') }, TypeError);
+  });
+
+  it('converts the output of a future on the JS thread', function () {
+    return Promise.all([addon.delay_object(1), addon.delay_object(2)]).then(function(results) {
+      assert.deepEqual(results, [{ doubled: 2 }, { doubled: 4 }]);
+    });
+  });
+
+  it('rejects a promise when a Rust future panics', function () {
+    return addon.panic_in_future().then(function() {
+      assert.fail('promise should have been rejected');
+    }, function(err) {
+      assert.match(err.message, /panicked/);
+    });
+  });
+
+  it('rejects a promise when the completion throws', function () {
+    return addon.throw_after_future().then(function() {
+      assert.fail('promise should have been rejected');
+    }, function(err) {
+      assert.instanceOf(err, TypeError);
+      assert.equal(err.message, 'bad completion');
+    });
+  });
+});
@@ -0,0 +1,46 @@
+var addon = require('../native');
+var assert = require('chai').assert;
+
+describe('JsRegExp', function() {
+  it('return a JsRegExp built in Rust', function () {
+    var re = addon.return_js_regexp('^a+b$', 'gi');
+    assert.instanceOf(re, RegExp);
+    assert.equal(re.source, '^a+b$');
+    assert.equal(re.flags, 'gi');
+    assert.isTrue(re.test('AAB'));
+  });
+
+  it('throws a SyntaxError for an invalid pattern', function () {
+    assert.throws(function() { addon.return_js_regexp('(', '') }, SyntaxError);
+  });
+
+  it('throws a SyntaxError for invalid flags', function () {
+    assert.throws(function() { addon.return_js_regexp('a', 'x') }, SyntaxError);
+    assert.throws(function() { addon.return_js_regexp('a', 'gg') }, SyntaxError);
+    assert.throws(function() { addon.return_js_regexp('a', 'uv') }, SyntaxError);
+  });
+
+  ('hasIndices' in RegExp.prototype ? it : it.skip)('supports the d flag', function () {
+    var re = addon.return_js_regexp('b', 'dg');
+    assert.isTrue(re.hasIndices);
+    assert.deepEqual(addon.read_js_regexp(re), ['b', 'dg']);
+    assert.deepEqual(addon.read_js_regexp(/a/dy), ['a', 'dy']);
+  });
+
+  ('unicodeSets' in RegExp.prototype ? it : it.skip)('supports the v flag', function () {
+    var re = addon.return_js_regexp('[\\p{L}--a]', 'v');
+    assert.isTrue(re.unicodeSets);
+    assert.isTrue(re.test('b'));
+    assert.isFalse(re.test('a'));
+    assert.deepEqual(addon.read_js_regexp(re), ['[\\p{L}--a]', 'v']);
+  });
+
+  it('reads the source and flags of a RegExp', function () {
+    assert.deepEqual(addon.read_js_regexp(/\d+\/x/ym), ['\\d+\\/x', 'my']);
+    assert.deepEqual(addon.read_js_regexp(new RegExp('')), ['(?:)', '']);
+  });
+
+  it('throws a TypeError when given a string', function () {
+    assert.throws(function() { addon.read_js_regexp('abc') }, TypeError);
+  });
+});
@@ -5,4 +5,41 @@ describe('JsString', function() {
   it('should return a JsString built in Rust', function () {
     assert.equal(addon.return_js_string(), "hello node");
   });
+
+  it('measures length in UTF-16 code units', function () {
+    assert.equal(addon.js_string_length(''), 0);
+    assert.equal(addon.js_string_length('abc'), 3);
+    assert.equal(addon.js_string_length('\u{1F600}'), 2);
+  });
+
+  it('converts to and from UTF-16 preserving lone surrogates', function () {
+    assert.equal(addon.reverse_js_string_utf16('abc'), 'cba');
+    assert.equal(addon.reverse_js_string_utf16('a\uD800b'), 'b\uD800a');
+    assert.equal(addon.reverse_js_string_utf16(''), '');
+  });
+
+  it('converts to and from Latin-1', function () {
+    assert.equal(addon.return_js_string_from_latin1(), 'café');
+    assert.deepEqual(addon.js_string_latin1_bytes('café'), [99, 97, 102, 233]);
+    assert.isNull(addon.js_string_latin1_bytes('€'));
+  });
+
+  it('appends values to a reused buffer', function () {
+    assert.equal(addon.concat_js_strings('hello', ' ', 'nöde', ' \u{1F600}'), 'hello nöde \u{1F600}');
+    assert.equal(addon.concat_js_strings(), '');
+  });
+
+  it('detects lone surrogates', function () {
+    assert.equal(addon.check_js_string('\u{1F600} ok'), '\u{1F600} ok');
+    assert.equal(addon.check_js_string('café'), 'café');
+    assert.throws(function() { addon.check_js_string('a\uDC00') }, TypeError, /^lone surrogate in "a�"$/);
+  });
+
+  it('returns external strings', function () {
+    var strings = addon.return_external_js_strings();
+    assert.equal(strings[0], 'hello from the binary');
+    assert.equal(strings[1], 'héllo');
+    assert.equal(strings[2], 'x'.repeat(1000));
+    assert.equal(strings[0] + '!', 'hello from the binary!');
+  });
 });
@@ -39,4 +39,155 @@ describe('Task', function() {
       });
     });
   });
+
+  describe('cancellation', function() {
+    function assertAbortError(err) {
+      assert.instanceOf(err, Error);
+      assert.equal(err.name, 'AbortError');
+      assert.equal(err.code, 'ABORT_ERR');
+    }
+
+    function fakeSignal(aborted) {
+      return {
+        aborted: aborted,
+        listeners: [],
+        addEventListener: function(type, listener) {
+          assert.equal(type, 'abort');
+          this.listeners.push(listener);
+        },
+        removeEventListener: function(type, listener) {
+          assert.equal(type, 'abort');
+          this.listeners = this.listeners.filter(function(l) { return l !== listener; });
+        },
+        abort: function() {
+          this.aborted = true;
+          this.listeners.forEach(function(listener) { listener(); });
+        }
+      };
+    }
+
+    it('completes a cancellable task that is not cancelled', function (done) {
+      addon.perform_cancellable_task(1, function(err, n) {
+        assert.isNull(err);
+        assert.equal(n, 1);
+        done();
+      });
+    });
+
+    it('cancels a task before it starts', function (done) {
+      // Occupy every thread in the pool, so the task is still queued when it's cancelled.
+      var size = parseInt(process.env.UV_THREADPOOL_SIZE, 10) || 4;
+      var pending = size + 1;
+      function finish() {
+        if (--pending === 0) {
+          done();
+        }
+      }
+      var blockers = [];
+      for (var i = 0; i < size; i++) {
+        blockers.push(addon.perform_cancellable_task(10000, function(err) {
+          assertAbortError(err);
+          finish();
+        }));
+      }
+      var performed = addon.counted_tasks_performed();
+      var handle = addon.perform_counted_task(function(err, n) {
+        assertAbortError(err);
+        assert.isUndefined(n);
+        assert.equal(addon.counted_tasks_performed(), performed);
+        finish();
+      });
+      addon.cancel_task(handle);
+      blockers.forEach(function(blocker) { addon.cancel_task(blocker); });
+    });
+
+    it('cancels a running task', function (done) {
+      var start = Date.now();
+      var handle = addon.perform_cancellable_task(10000, function(err) {
+        assertAbortError(err);
+        assert.isBelow(Date.now() - start, 5000);
+        done();
+      });
+      setTimeout(function() { addon.cancel_task(handle); }, 20);
+    });
+
+    it('ignores cancellation after the task completes', function (done) {
+      var handle = addon.perform_cancellable_task(0, function(err, n) {
+        assert.isNull(err);
+        setImmediate(function() {
+          addon.cancel_task(handle);
+          done();
+        });
+      });
+    });
+
+    it('cancels a task when its signal is aborted', function (done) {
+      var signal = fakeSignal(false);
+      addon.perform_task_with_signal(10000, signal, function(err) {
+        assertAbortError(err);
+        assert.lengthOf(signal.listeners, 0);
+        done();
+      });
+      assert.lengthOf(signal.listeners, 1);
+      setTimeout(function() { signal.abort(); }, 20);
+    });
+
+    it('does not perform a task whose signal is already aborted', function (done) {
+      var signal = fakeSignal(true);
+      addon.perform_task_with_signal(10000, signal, function(err) {
+        assertAbortError(err);
+        done();
+      });
+      assert.lengthOf(signal.listeners, 0);
+    });
+
+    it('does not perform a task whose signal is already aborted while the pool is idle', function (done) {
+      var signal = fakeSignal(true);
+      var performed = addon.counted_tasks_performed();
+      var called = false;
+      addon.perform_counted_task_with_signal(signal, function(err, n) {
+        assertAbortError(err);
+        assert.isUndefined(n);
+        called = true;
+      });
+      assert.isFalse(called);
+      assert.lengthOf(signal.listeners, 0);
+      // Give an idle pool thread time to run the task, if it had been queued.
+      setTimeout(function() {
+        assert.isTrue(called);
+        assert.equal(addon.counted_tasks_performed(), performed);
+        done();
+      }, 50);
+    });
+
+    it('does not schedule a task if adding the abort listener fails', function (done) {
+      var called = false;
+      assert.throws(function() {
+        addon.perform_task_with_signal(1, { aborted: false }, function() { called = true; });
+      }, TypeError);
+      setTimeout(function() {
+        assert.isFalse(called);
+        done();
+      }, 50);
+    });
+
+    it('removes the abort listener when the task completes', function (done) {
+      var signal = fakeSignal(false);
+      addon.perform_task_with_signal(1, signal, function(err, n) {
+        assert.isNull(err);
+        assert.equal(n, 1);
+        assert.lengthOf(signal.listeners, 0);
+        done();
+      });
+    });
+
+    (typeof AbortController === 'function' ? it : it.skip)('accepts an AbortSignal', function (done) {
+      var controller = new AbortController();
+      addon.perform_task_with_signal(10000, controller.signal, function(err) {
+        assertAbortError(err);
+        done();
+      });
+      controller.abort();
+    });
+  });
 });
@@ -0,0 +1,162 @@
+var addon = require('../native');
+var assert = require('chai').assert;
+
+describe('JsValue', function() {
+  it('compares values with ===, == and Object.is', function () {
+    assert.deepEqual(addon.compare_values('a', 'a'), [true, true, true]);
+    assert.deepEqual(addon.compare_values(1, '1'), [false, true, false]);
+    assert.deepEqual(addon.compare_values(null, undefined), [false, true, false]);
+    assert.deepEqual(addon.compare_values(NaN, NaN), [false, false, true]);
+    assert.deepEqual(addon.compare_values(0, -0), [true, true, false]);
+    assert.deepEqual(addon.compare_values({}, {}), [false, false, false]);
+  });
+
+  it('propagates exceptions thrown by a loose comparison', function () {
+    var o = { valueOf: function() { throw new RangeError('no valueOf for you'); } };
+    assert.throws(function() { addon.compare_values(o, 1) }, RangeError, /no valueOf for you/);
+  });
+
+  it('computes the same result as typeof', function () {
+    var values = [undefined, null, true, 17, 'hi', Symbol('s'), function() {}, class {}, {}, [], new Date()];
+    values.forEach(function(v) {
+      assert.equal(addon.type_of(v), typeof v);
+    });
+  });
+
+  it('computes the same result as instanceof', function () {
+    function Point() {}
+    assert.isTrue(addon.instance_of(new Point(), Point));
+    assert.isTrue(addon.instance_of(new Point(), Object));
+    assert.isFalse(addon.instance_of({}, Point));
+    assert.isFalse(addon.instance_of(17, Number));
+  });
+
+  it('respects Symbol.hasInstance in instanceof', function () {
+    var Even = function() {};
+    Object.defineProperty(Even, Symbol.hasInstance, { value: function(n) { return n % 2 === 0; } });
+    assert.isTrue(addon.instance_of(2, Even));
+    assert.isFalse(addon.instance_of(3, Even));
+  });
+
+  it('converts values to numbers', function () {
+    assert.equal(addon.convert_to_number('42'), 42);
+    assert.equal(addon.convert_to_number(true), 1);
+    assert.equal(addon.convert_to_number(null), 0);
+    assert.isNaN(addon.convert_to_number(undefined));
+    assert.equal(addon.convert_to_number({ valueOf: function() { return 7; } }), 7);
+    assert.throws(function() { addon.convert_to_number(Symbol('s')) }, TypeError);
+  });
+
+  it('converts values to booleans', function () {
+    assert.isFalse(addon.convert_to_boolean(0));
+    assert.isFalse(addon.convert_to_boolean(''));
+    assert.isFalse(addon.convert_to_boolean(null));
+    assert.isTrue(addon.convert_to_boolean('0'));
+    assert.isTrue(addon.convert_to_boolean({}));
+  });
+
+  it('converts values to objects', function () {
+    var o = {};
+    assert.strictEqual(addon.convert_to_object(o), o);
+    assert.instanceOf(addon.convert_to_object('hi'), String);
+    assert.instanceOf(addon.convert_to_object(17), Number);
+    assert.throws(function() { addon.convert_to_object(null) }, TypeError);
+    assert.throws(function() { addon.convert_to_object(undefined) }, TypeError);
+  });
+
+  it('converts values to integers', function () {
+    assert.deepEqual(addon.convert_to_integers(-3.7), [-3, -3, 4294967293]);
+    assert.deepEqual(addon.convert_to_integers(NaN), [0, 0, 0]);
+    assert.deepEqual(addon.convert_to_integers(Math.pow(2, 32) + 5), [Math.pow(2, 32) + 5, 5, 5]);
+    assert.deepEqual(addon.convert_to_integers('12.5'), [12, 12, 12]);
+  });
+
+  it('converts values to primitives', function () {
+    assert.equal(addon.convert_to_primitive('hi', 'default'), 'hi');
+    var o = { valueOf: function() { return 1; }, toString: function() { return 'one'; } };
+    assert.equal(addon.convert_to_primitive(o, 'number'), 1);
+    assert.equal(addon.convert_to_primitive(o, 'default'), 1);
+    assert.equal(addon.convert_to_primitive(o, 'string'), 'one');
+  });
+
+  it('passes the hint to Symbol.toPrimitive', function () {
+    var o = {};
+    o[Symbol.toPrimitive] = function(hint) { return hint; };
+    assert.equal(addon.convert_to_primitive(o, 'number'), 'number');
+    assert.equal(addon.convert_to_primitive(o, 'string'), 'string');
+    assert.equal(addon.convert_to_primitive(o, 'default'), 'default');
+  });
+
+  it('throws when an object cannot be converted to a primitive', function () {
+    var o = { valueOf: function() { return {}; }, toString: function() { return {}; } };
+    assert.throws(function() { addon.convert_to_primitive(o, 'default') }, TypeError);
+    var p = {};
+    p[Symbol.toPrimitive] = function() { throw new RangeError('nope'); };
+    assert.throws(function() { addon.convert_to_primitive(p, 'default') }, RangeError, /nope/);
+  });
+
+  it('classifies values into variants', function () {
+    assert.equal(addon.variant_of(undefined), 'undefined');
+    assert.equal(addon.variant_of(null), 'null');
+    assert.equal(addon.variant_of(false), 'boolean');
+    assert.equal(addon.variant_of(3.5), 'number');
+    assert.equal(addon.variant_of('hi'), 'string');
+    assert.equal(addon.variant_of([1, 2]), 'array');
+    assert.equal(addon.variant_of(function() {}), 'function');
+    assert.equal(addon.variant_of(Buffer.alloc(4)), 'buffer');
+    assert.equal(addon.variant_of(new ArrayBuffer(4)), 'arraybuffer');
+    assert.equal(addon.variant_of(new TypeError('oops')), 'error');
+    assert.equal(addon.variant_of({}), 'object');
+    assert.equal(addon.variant_of(new Map()), 'object');
+    assert.equal(addon.variant_of(Symbol('s')), 'other');
+  });
+
+  it('parses JSON', function() {
+    assert.deepEqual(addon.parse_json('{"a":[1,2,{"b":null}],"c":"d"}'), { a: [1, 2, { b: null }], c: 'd' });
+    assert.strictEqual(addon.parse_json(' 17 '), 17);
+    assert.strictEqual(addon.parse_json('"\\u00e9"'), '\u00e9');
+    assert.throws(function() { addon.parse_json('{a:1}') }, SyntaxError);
+    assert.throws(function() { addon.parse_json('') }, SyntaxError);
+  });
+
+  it('stringifies JSON', function() {
+    var value = { a: [1, 'two'], b: { c: true }, d: undefined };
+    assert.equal(addon.stringify_json(value, 0), JSON.stringify(value));
+    assert.equal(addon.stringify_json(value, 2), JSON.stringify(value, null, 2));
+    assert.equal(addon.stringify_json(value, 20), JSON.stringify(value, null, 10));
+    assert.equal(addon.stringify_json({ toJSON: function() { return 'custom'; } }, 0), '"custom"');
+    assert.equal(addon.stringify_json(null, 0), 'null');
+    assert.throws(function() { addon.stringify_json(undefined, 0) }, TypeError, /cannot be converted to JSON/);
+    assert.throws(function() { addon.stringify_json(function() {}, 0) }, TypeError, /cannot be converted to JSON/);
+    var cycle = {};
+    cycle.self = cycle;
+    assert.throws(function() { addon.stringify_json(cycle, 0) }, TypeError);
+  });
+
+  describe('serialization', function() {
+    var v8 = require('v8');
+    var supported = typeof v8.serialize === 'function';
+
+    (supported ? it : it.skip)('round-trips values', function() {
+      var value = { a: [1, 'two', null], b: new Map([[1, { c: true }]]), d: new Date(0), e: /x/g };
+      value.self = value;
+      var copy = addon.deserialize_value(addon.serialize_value(value));
+      assert.deepEqual(copy.a, value.a);
+      assert.deepEqual(Array.from(copy.b), Array.from(value.b));
+      assert.equal(copy.d.getTime(), 0);
+      assert.equal(copy.e.source, 'x');
+      assert.strictEqual(copy.self, copy);
+    });
+
+    (supported ? it : it.skip)('is compatible with v8.serialize', function() {
+      var value = { list: [1, 2, 3], text: 'héllo' };
+      assert.deepEqual(v8.deserialize(addon.serialize_value(value)), value);
+      assert.deepEqual(addon.deserialize_value(v8.serialize(value)), value);
+    });
+
+    (supported ? it : it.skip)('throws for values that cannot be cloned', function() {
+      assert.throws(function() { addon.serialize_value({ f: function() {} }) }, Error);
+      assert.throws(function() { addon.deserialize_value(Buffer.from([1, 2, 3])) }, Error);
+    });
+  });
+});
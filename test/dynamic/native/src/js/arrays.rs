@@ -1,6 +1,6 @@
 use neon::vm::{FunctionContext, JsResult, Context};
 use neon::mem::Handle;
-use neon::js::{JsArray, Object};
+use neon::js::{JsArray, JsNumber, JsString, JsValue, JsVariant, Object};
 
 pub fn return_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
     Ok(cx.empty_array())
@@ -19,3 +19,90 @@ pub fn return_js_array_with_string(mut cx: FunctionContext) -> JsResult<JsArray>
     array.set(&mut cx, 0, s)?;
     Ok(array)
 }
+
+pub fn return_js_array_from_iter(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let strings = vec![cx.string("a"), cx.string("b"), cx.string("c")];
+    JsArray::from_iter(&mut cx, strings)
+}
+
+pub fn return_js_array_from_slice(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let numbers = JsArray::from_slice(&mut cx, &[1.5, 2.5])?;
+    let words = JsArray::from_slice(&mut cx, &["x", "y"])?;
+    let flags = JsArray::from_slice(&mut cx, &[true, false])?;
+    JsArray::from_slice(&mut cx, &[numbers, words, flags])
+}
+
+pub fn push_to_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let value: Handle<JsValue> = cx.argument(1)?;
+    let len = array.push(&mut cx, value)?;
+    Ok(cx.number(len))
+}
+
+pub fn sum_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut sum = 0.0;
+    for i in 0..array.len() {
+        sum += array.get_as::<_, JsNumber>(&mut cx, i)?.value();
+    }
+    Ok(cx.number(sum))
+}
+
+pub fn join_js_array(mut cx: FunctionContext) -> JsResult<JsString> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut parts = Vec::new();
+    for element in array.iter(&mut cx) {
+        parts.push(match element?.variant() {
+            JsVariant::String(s) => s.value(),
+            JsVariant::Number(n) => n.value().to_string(),
+            JsVariant::Boolean(b) => b.value().to_string(),
+            _ => "?".to_string()
+        });
+    }
+    Ok(cx.string(parts.join(",")))
+}
+
+pub fn return_js_arrays_from_numeric_slices(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let floats = JsArray::from_f64_slice(&mut cx, &[0.5, -1.0, 1e300])?;
+    let signed = JsArray::from_i32_slice(&mut cx, &[::std::i32::MIN, -1, 0, ::std::i32::MAX])?;
+    let unsigned = JsArray::from_u32_slice(&mut cx, &[0, 1, ::std::u32::MAX])?;
+    JsArray::from_slice(&mut cx, &[floats, signed, unsigned])
+}
+
+pub fn double_f64_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let numbers: Vec<f64> = array.to_f64_vec(&mut cx)?.into_iter().map(|n| n * 2.0).collect();
+    JsArray::from_f64_slice(&mut cx, &numbers)
+}
+
+pub fn negate_i32_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let numbers: Vec<i32> = array.to_i32_vec(&mut cx)?.into_iter().map(|n| n.wrapping_neg()).collect();
+    JsArray::from_i32_slice(&mut cx, &numbers)
+}
+
+pub fn sum_u32_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let sum: f64 = array.to_u32_vec(&mut cx)?.into_iter().map(|n| n as f64).sum();
+    Ok(cx.number(sum))
+}
+
+pub fn copy_js_array_by_element(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut numbers = Vec::with_capacity(array.len() as usize);
+    for i in 0..array.len() {
+        numbers.push(array.get_as::<_, JsNumber>(&mut cx, i)?.value());
+    }
+    let result = JsArray::new(&mut cx, numbers.len() as u32);
+    for (i, n) in numbers.into_iter().enumerate() {
+        let n = cx.number(n);
+        result.set(&mut cx, i as u32, n)?;
+    }
+    Ok(result)
+}
+
+pub fn copy_js_array_in_bulk(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let numbers = array.to_f64_vec(&mut cx)?;
+    JsArray::from_f64_slice(&mut cx, &numbers)
+}
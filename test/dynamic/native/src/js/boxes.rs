@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+use neon::vm::{FunctionContext, JsResult, Context};
+use neon::mem::Handle;
+use neon::js::{JsBoolean, JsNumber, JsString, JsUndefined, JsValue};
+use neon::js::boxed::JsBox;
+
+pub struct Person {
+    name: String
+}
+
+pub fn box_person(mut cx: FunctionContext) -> JsResult<JsBox<Person>> {
+    let name = cx.argument::<JsString>(0)?.value();
+    JsBox::new(&mut cx, Person { name: name })
+}
+
+pub fn person_name(mut cx: FunctionContext) -> JsResult<JsString> {
+    let person: Handle<JsBox<Person>> = cx.argument(0)?;
+    let name = person.name.clone();
+    Ok(cx.string(name))
+}
+
+pub fn box_counter(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<f64>>> {
+    JsBox::new(&mut cx, RefCell::new(0.0))
+}
+
+pub fn increment_counter(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let counter: Handle<JsBox<RefCell<f64>>> = cx.argument(0)?;
+    *counter.borrow_mut() += 1.0;
+    let n = *counter.borrow();
+    Ok(cx.number(n))
+}
+
+pub fn is_boxed_person(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let b = v.is_a::<JsBox<Person>>();
+    Ok(cx.boolean(b))
+}
+
+pub fn require_boxed_person(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.argument::<JsBox<Person>>(0)?;
+    Ok(cx.undefined())
+}
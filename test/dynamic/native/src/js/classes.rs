@@ -1,7 +1,9 @@
-use neon::js::{JsString, JsNumber, Borrow};
+use neon::js::{JsString, JsNumber, JsValue, Borrow};
+use neon::js::class::Class;
 use neon::mem::Handle;
 use neon::js::error::{JsError, Kind};
-use neon::vm::Context;
+use neon::serialize::Serializable;
+use neon::vm::{Context, VmResult, JsResult};
 
 pub struct User {
   id: i32,
@@ -95,3 +97,27 @@ declare_types! {
     }
   }
 }
+
+impl Serializable for JsUser {
+  fn serialize<'a, C: Context<'a>>(cx: &mut C, this: Handle<'a, Self>) -> VmResult<Vec<u8>> {
+    let guard = cx.lock();
+    let user = this.borrow(&guard);
+    Ok(format!("{}\n{}\n{}\n{}", user.id, user.first_name, user.last_name, user.email).into_bytes())
+  }
+
+  fn deserialize<'a, C: Context<'a>>(cx: &mut C, data: &[u8]) -> JsResult<'a, Self> {
+    let text = String::from_utf8_lossy(data).into_owned();
+    let fields: Vec<&str> = text.split('\n').collect();
+    if fields.len() != 4 {
+      return JsError::throw(cx, Kind::Error, "invalid User data");
+    }
+    let id = fields[0].parse::<f64>().unwrap_or(0.0);
+    let args: Vec<Handle<JsValue>> = vec![
+      cx.number(id).upcast(),
+      cx.string(fields[1]).upcast(),
+      cx.string(fields[2]).upcast(),
+      cx.string(fields[3]).upcast()
+    ];
+    JsUser::new(cx, args)
+  }
+}
@@ -0,0 +1,130 @@
+use neon::vm::{FunctionContext, JsResult, JsResultExt, Context};
+use neon::mem::Handle;
+use neon::js::{JsArray, JsBoolean, JsNumber, JsObject, JsValue, Object};
+use neon::js::collection::{JsMap, JsSet};
+use neon::js::iter;
+
+pub fn return_js_map_with_object_key(mut cx: FunctionContext) -> JsResult<JsMap> {
+    let map = JsMap::new(&mut cx);
+    let key: Handle<JsObject> = cx.argument(0)?;
+    let n = cx.number(9000);
+    map.set(&mut cx, key, n)?;
+    let one = cx.number(1);
+    let s = cx.string("one");
+    map.set(&mut cx, one, s)?;
+    Ok(map)
+}
+
+pub fn read_js_map_with_object_key(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let map: Handle<JsMap> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+    map.get(&mut cx, key)
+}
+
+pub fn delete_from_js_map(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let map: Handle<JsMap> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+    let had = map.has(&mut cx, key)?;
+    let deleted = map.delete(&mut cx, key)?;
+    let has = map.has(&mut cx, key)?;
+    Ok(cx.boolean(had && deleted && !has))
+}
+
+pub fn js_map_entries(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let map: Handle<JsMap> = cx.argument(0)?;
+    let entries = map.to_vec(&mut cx)?;
+    let result = JsArray::new(&mut cx, entries.len() as u32);
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        let pair = JsArray::new(&mut cx, 2);
+        pair.set(&mut cx, 0, key)?;
+        pair.set(&mut cx, 1, value)?;
+        result.set(&mut cx, i as u32, pair)?;
+    }
+    Ok(result)
+}
+
+pub fn clear_js_map(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let map: Handle<JsMap> = cx.argument(0)?;
+    map.clear(&mut cx);
+    let size = map.size();
+    Ok(cx.number(size))
+}
+
+pub fn return_js_set(mut cx: FunctionContext) -> JsResult<JsSet> {
+    let set = JsSet::new(&mut cx);
+    for x in 1..4 {
+        let n = cx.number(x);
+        set.add(&mut cx, n)?;
+    }
+    let duplicate = cx.number(2);
+    set.add(&mut cx, duplicate)?;
+    Ok(set)
+}
+
+pub fn js_set_values(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let set: Handle<JsSet> = cx.argument(0)?;
+    let values = set.to_vec(&mut cx)?;
+    let result = JsArray::new(&mut cx, values.len() as u32);
+    for (i, value) in values.into_iter().enumerate() {
+        result.set(&mut cx, i as u32, value)?;
+    }
+    Ok(result)
+}
+
+pub fn delete_from_js_set(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let set: Handle<JsSet> = cx.argument(0)?;
+    let value: Handle<JsValue> = cx.argument(1)?;
+    if set.has(&mut cx, value)? {
+        set.delete(&mut cx, value)?;
+    }
+    let size = set.size();
+    Ok(cx.number(size))
+}
+
+pub fn is_js_map_or_set(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    let result = JsArray::new(&mut cx, 2);
+    let is_map = cx.boolean(value.is_a::<JsMap>());
+    let is_set = cx.boolean(value.is_a::<JsSet>());
+    result.set(&mut cx, 0, is_map)?;
+    result.set(&mut cx, 1, is_set)?;
+    Ok(result)
+}
+
+pub fn collect_iterable(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let iterable: Handle<JsValue> = cx.argument(0)?;
+    let limit = match cx.argument_opt(1) {
+        Some(arg) => Some(arg.downcast::<JsNumber>().unwrap_or_throw(&mut cx)?.value() as u32),
+        None => None
+    };
+    let result = JsArray::new(&mut cx, 0);
+    let mut len = 0;
+    cx.iterate(iterable, |cx, item| {
+        if limit == Some(len) {
+            return Ok(false);
+        }
+        result.set(cx, len, item)?;
+        len += 1;
+        Ok(true)
+    })?;
+    Ok(result)
+}
+
+pub fn sum_iterable(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let iterable: Handle<JsValue> = cx.argument(0)?;
+    let mut total = 0.0;
+    cx.iterate(iterable, |cx, item| {
+        total += item.downcast::<JsNumber>().unwrap_or_throw(cx)?.value();
+        Ok(true)
+    })?;
+    Ok(cx.number(total))
+}
+
+pub fn return_rust_iterator(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let n = cx.argument::<JsNumber>(0)?.value() as u32;
+    iter::from_iter(&mut cx, (0..n).map(|i| format!("row {}", i)))
+}
+
+pub fn return_infinite_rust_iterator(mut cx: FunctionContext) -> JsResult<JsObject> {
+    iter::from_iter(&mut cx, (1..).map(|n: u32| n * n))
+}
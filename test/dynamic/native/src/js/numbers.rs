@@ -1,5 +1,5 @@
 use neon::vm::{FunctionContext, JsResult, Context};
-use neon::js::JsNumber;
+use neon::js::{JsArray, JsNumber, JsString, JsValue, Object};
 use neon::mem::Handle;
 
 pub fn return_js_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
@@ -41,3 +41,37 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn return_js_integers(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let a = JsNumber::from_i32(&mut cx, -2147483648);
+    let b = JsNumber::from_u32(&mut cx, 4294967295);
+    let result = JsArray::new(&mut cx, 2);
+    result.set(&mut cx, 0, a)?;
+    result.set(&mut cx, 1, b)?;
+    Ok(result)
+}
+
+pub fn classify_js_number(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n: Handle<JsNumber> = cx.argument(0)?;
+    let int32 = cx.boolean(n.is_int32());
+    let uint32 = cx.boolean(n.is_uint32());
+    let safe = cx.boolean(n.is_safe_integer());
+    let result = JsArray::new(&mut cx, 3);
+    result.set(&mut cx, 0, int32)?;
+    result.set(&mut cx, 1, uint32)?;
+    result.set(&mut cx, 2, safe)?;
+    Ok(result)
+}
+
+pub fn convert_js_number(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let n: Handle<JsNumber> = cx.argument(0)?;
+    let ty = cx.argument::<JsString>(1)?.value();
+    let result = match &ty[..] {
+        "i32" => n.to_i32(&mut cx)?.to_string(),
+        "u32" => n.to_u32(&mut cx)?.to_string(),
+        "i64" => n.to_i64(&mut cx)?.to_string(),
+        "u64" => n.to_u64(&mut cx)?.to_string(),
+        _ => n.to_usize(&mut cx)?.to_string()
+    };
+    Ok(cx.string(result).upcast())
+}
@@ -1,7 +1,10 @@
+use std::cell::Cell;
+use std::rc::Rc;
 use neon::vm::{FunctionContext, JsResult, Context};
 use neon::mem::Handle;
-use neon::js::{JsUndefined, JsObject, Object, JsNumber, Borrow, BorrowMut};
+use neon::js::{JsUndefined, JsObject, Object, JsNumber, JsBoolean, JsString, JsFunction, JsValue, JsArray, PropertyDescriptor, Value, Borrow, BorrowMut};
 use neon::js::binary::{JsArrayBuffer, JsBuffer};
+use neon::js::property::{PropertyOptions, ObjectShape, PropertyName};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global())
@@ -34,6 +37,50 @@ pub fn return_js_object_with_string(mut cx: FunctionContext) -> JsResult<JsObjec
     Ok(js_object)
 }
 
+pub fn return_object_from_entries(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let number = cx.number(9000).upcast();
+    let string = cx.string("hello node").upcast();
+    let first = cx.boolean(false).upcast();
+    let last = cx.boolean(true).upcast();
+    JsObject::from_entries(&mut cx, &[("number", number), ("string", string), ("flag", first), ("flag", last)])
+}
+
+pub fn return_records_with_shape(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let count = cx.argument::<JsNumber>(0)?.value() as u32;
+    let shape = ObjectShape::new(&["id", "name", "even"]);
+    let records = JsArray::new(&mut cx, count);
+    for i in 0..count {
+        let id = cx.number(i).upcast();
+        let name = cx.string(format!("record {}", i)).upcast();
+        let even = cx.boolean(i % 2 == 0).upcast();
+        let record = shape.build(&mut cx, &[id, name, even])?;
+        records.set(&mut cx, i, record)?;
+    }
+    Ok(records)
+}
+
+pub fn build_shape_with_missing_value(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let id = cx.number(1).upcast();
+    ObjectShape::new(&["id", "name"]).build(&mut cx, &[id])
+}
+
+static COUNT: PropertyName = property_name!("count");
+
+pub fn increment_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let count = obj.get(&mut cx, COUNT)?.to_number(&mut cx)?.value() + 1.0;
+    let count = cx.number(count);
+    obj.set(&mut cx, COUNT, count)?;
+    Ok(count)
+}
+
+pub fn return_interned_names(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let first = cx.intern("count");
+    let second = COUNT.to_js_string(&mut cx);
+    let other = cx.intern("other");
+    JsArray::from_slice(&mut cx, &[first, second, other])
+}
+
 pub fn return_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
     let b: Handle<JsArrayBuffer> = cx.array_buffer(16)?;
     Ok(b)
@@ -118,3 +165,169 @@ pub fn write_buffer_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsUndef
     cx.borrow_mut(&mut b, |data| { data.as_mut_slice::<u32>()[i] = x; });
     Ok(cx.undefined())
 }
+
+pub fn has_properties(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+    let has = o.has(&mut cx, key)?;
+    let has_own = o.has_own(&mut cx, key)?;
+    let has_index = o.has_own(&mut cx, 0)?;
+    let result = JsArray::new(&mut cx, 3);
+    let has = cx.boolean(has);
+    let has_own = cx.boolean(has_own);
+    let has_index = cx.boolean(has_index);
+    result.set(&mut cx, 0, has)?;
+    result.set(&mut cx, 1, has_own)?;
+    result.set(&mut cx, 2, has_index)?;
+    Ok(result)
+}
+
+pub fn delete_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsString> = cx.argument(1)?;
+    let deleted = o.delete(&mut cx, &key.value()[..])?;
+    Ok(cx.boolean(deleted))
+}
+
+pub fn define_constant(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsString> = cx.argument(1)?;
+    let value: Handle<JsValue> = cx.argument(2)?;
+    let defined = o.define_property(&mut cx, key, PropertyDescriptor::Data {
+        value: value,
+        writable: false,
+        enumerable: true,
+        configurable: false
+    })?;
+    Ok(cx.boolean(defined))
+}
+
+pub fn define_hidden_accessor(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsString> = cx.argument(1)?;
+    let get: Handle<JsFunction> = cx.argument(2)?;
+    let set = cx.argument_opt(3).and_then(|f| f.downcast::<JsFunction>().ok());
+    let defined = o.define_property(&mut cx, key, PropertyDescriptor::Accessor {
+        get: Some(get),
+        set: set,
+        enumerable: false,
+        configurable: true
+    })?;
+    Ok(cx.boolean(defined))
+}
+
+pub fn describe_property(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+    let result = cx.empty_object();
+    let (enumerable, configurable) = match o.get_own_property_descriptor(&mut cx, key)? {
+        None => { return Ok(cx.undefined().upcast()); }
+        Some(PropertyDescriptor::Data { value, writable, enumerable, configurable }) => {
+            let writable = cx.boolean(writable);
+            result.set(&mut cx, "value", value)?;
+            result.set(&mut cx, "writable", writable)?;
+            (enumerable, configurable)
+        }
+        Some(PropertyDescriptor::Accessor { get, set, enumerable, configurable }) => {
+            let get = get.map(|f| f.upcast()).unwrap_or(cx.undefined().upcast::<JsValue>());
+            let set = set.map(|f| f.upcast()).unwrap_or(cx.undefined().upcast::<JsValue>());
+            result.set(&mut cx, "get", get)?;
+            result.set(&mut cx, "set", set)?;
+            (enumerable, configurable)
+        }
+    };
+    let enumerable = cx.boolean(enumerable);
+    let configurable = cx.boolean(configurable);
+    result.set(&mut cx, "enumerable", enumerable)?;
+    result.set(&mut cx, "configurable", configurable)?;
+    Ok(result.upcast())
+}
+
+pub fn create_with_prototype(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let proto: Handle<JsValue> = cx.argument(0)?;
+    JsObject::create(&mut cx, proto)
+}
+
+pub fn get_prototype(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    Ok(o.get_prototype(&mut cx))
+}
+
+pub fn set_prototype(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let proto: Handle<JsValue> = cx.argument(1)?;
+    let b = o.set_prototype(&mut cx, proto)?;
+    Ok(cx.boolean(b))
+}
+
+pub fn restrict_object(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let level = cx.argument::<JsString>(1)?.value();
+    let b = match &level[..] {
+        "freeze" => o.freeze(&mut cx)?,
+        "seal" => o.seal(&mut cx)?,
+        _ => o.prevent_extensions(&mut cx)?
+    };
+    Ok(cx.boolean(b))
+}
+
+pub fn integrity_of(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let frozen = o.is_frozen(&mut cx)?;
+    let sealed = o.is_sealed(&mut cx)?;
+    let extensible = o.is_extensible(&mut cx)?;
+    let result = JsArray::new(&mut cx, 3);
+    let frozen = cx.boolean(frozen);
+    let sealed = cx.boolean(sealed);
+    let extensible = cx.boolean(extensible);
+    result.set(&mut cx, 0, frozen)?;
+    result.set(&mut cx, 1, sealed)?;
+    result.set(&mut cx, 2, extensible)?;
+    Ok(result)
+}
+
+pub fn list_properties(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let o: Handle<JsObject> = cx.argument(0)?;
+    let flags: Handle<JsObject> = cx.argument(1)?;
+    let mut options = PropertyOptions::new();
+    for &name in ["own_only", "enumerable_only", "configurable_only", "include_symbols", "include_indices"].iter() {
+        if let Ok(b) = flags.get(&mut cx, name)?.downcast::<JsBoolean>() {
+            options = match name {
+                "own_only" => options.own_only(b.value()),
+                "enumerable_only" => options.enumerable_only(b.value()),
+                "configurable_only" => options.configurable_only(b.value()),
+                "include_symbols" => options.include_symbols(b.value()),
+                _ => options.include_indices(b.value())
+            };
+        }
+    }
+    let entries = o.properties(&mut cx, options)?.collect::<Result<Vec<_>, _>>()?;
+    let result = JsArray::new(&mut cx, entries.len() as u32);
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        let entry = JsArray::new(&mut cx, 2);
+        entry.set(&mut cx, 0, key)?;
+        entry.set(&mut cx, 1, value)?;
+        result.set(&mut cx, i as u32, entry)?;
+    }
+    Ok(result)
+}
+
+fn get_answer(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(42))
+}
+
+pub fn return_object_with_accessors(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let o = cx.empty_object();
+    let count = Rc::new(Cell::new(0.0));
+    let (read, write) = (count.clone(), count.clone());
+    o.define_accessor(&mut cx, "count", move |mut cx| {
+        read.set(read.get() + 1.0);
+        Ok(cx.number(read.get()))
+    }, move |mut cx| {
+        write.set(cx.argument::<JsNumber>(0)?.value());
+        Ok(())
+    })?;
+    o.define_getter(&mut cx, "answer", get_answer)?;
+    o.define_getter(&mut cx, "self", |mut cx| Ok(cx.this()))?;
+    Ok(o)
+}
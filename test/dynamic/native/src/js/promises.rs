@@ -0,0 +1,143 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll, RawWaker, RawWakerVTable, Waker};
+use std::thread;
+use std::time::Duration;
+use neon::vm::{FunctionContext, JsResult, VmResult, Context};
+use neon::mem::Handle;
+use neon::js::{JsBoolean, JsNumber, JsObject, JsString, JsUndefined, JsValue};
+use neon::js::error::{JsError, Kind};
+use neon::js::promise::{JsPromise, JsFuture, Canceled};
+
+pub fn await_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    let promise = JsPromise::resolve(&mut cx, value)?;
+    promise.when_settled(&mut cx, |mut cx, settled| {
+        let (status, value) = match settled {
+            Ok(value) => ("fulfilled", value),
+            Err(reason) => ("rejected", reason)
+        };
+        let status = cx.string(status).upcast();
+        JsObject::from_entries(&mut cx, &[("status", status), ("value", value)])
+    })
+}
+
+pub fn is_promise(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    Ok(cx.boolean(value.is_a::<JsPromise>()))
+}
+
+thread_local! {
+    static FUTURE: RefCell<Option<JsFuture<Result<f64, String>>>> = RefCell::new(None);
+}
+
+pub fn promise_to_future(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let promise: Handle<JsPromise> = cx.argument(0)?;
+    let future = promise.to_future(&mut cx, |_, settled| {
+        match settled {
+            Ok(value) => value.downcast::<JsNumber>().map(|n| n.value()).map_err(|_| "not a number".to_string()),
+            Err(reason) => Err(reason.downcast::<JsString>().map(|s| s.value()).unwrap_or_default())
+        }
+    })?;
+    FUTURE.with(|cell| *cell.borrow_mut() = Some(future));
+    Ok(cx.undefined())
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker { RawWaker::new(ptr::null(), &VTABLE) }
+    fn noop(_: *const ()) { }
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+}
+
+pub fn poll_promise_future(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let waker = noop_waker();
+    let mut task_cx = TaskContext::from_waker(&waker);
+    let poll = FUTURE.with(|cell| {
+        let mut future = cell.borrow_mut();
+        let poll = Pin::new(future.as_mut().unwrap()).poll(&mut task_cx);
+        if poll.is_ready() {
+            *future = None;
+        }
+        poll
+    });
+    match poll {
+        Poll::Pending => Ok(cx.undefined().upcast()),
+        Poll::Ready(Ok(Ok(n))) => Ok(cx.number(n).upcast()),
+        Poll::Ready(Ok(Err(msg))) => JsError::throw(&mut cx, Kind::Error, &msg),
+        Poll::Ready(Err(Canceled)) => JsError::throw(&mut cx, Kind::Error, "canceled")
+    }
+}
+
+// A future that completes with a value after a delay, on another thread.
+pub struct Delay {
+    state: Arc<Mutex<(Option<Result<f64, String>>, Option<Waker>)>>
+}
+
+impl Delay {
+    fn new(ms: u64, result: Result<f64, String>) -> Delay {
+        let state = Arc::new(Mutex::new((None, None::<Waker>)));
+        let thread_state = state.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(ms));
+            let mut state = thread_state.lock().unwrap();
+            state.0 = Some(result);
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+        Delay { state: state }
+    }
+}
+
+impl Future for Delay {
+    type Output = Result<f64, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.0.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+pub struct Panic;
+
+impl Future for Panic {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _: &mut TaskContext) -> Poll<()> {
+        panic!("future panicked")
+    }
+}
+
+pub fn delay_number(cx: &mut FunctionContext) -> VmResult<Delay> {
+    let n = cx.argument::<JsNumber>(0)?.value();
+    let result = if n < 0.0 { Err(format!("negative number: {}", n)) } else { Ok(n) };
+    Ok(Delay::new(10, result))
+}
+
+pub fn delay_object(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value();
+    JsPromise::from_future(&mut cx, Delay::new(10, Ok(n)), |mut cx, result| {
+        let n = cx.number(result.unwrap() * 2.0).upcast();
+        JsObject::from_entries(&mut cx, &[("doubled", n)])
+    })
+}
+
+pub fn panic_in_future(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    JsPromise::from_future(&mut cx, Panic, |mut cx, _| Ok(cx.undefined()))
+}
+
+pub fn throw_after_future(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    JsPromise::from_future(&mut cx, Delay::new(0, Ok(0.0)), |mut cx, _| {
+        JsError::throw::<_, Handle<JsValue>>(&mut cx, Kind::TypeError, "bad completion")
+    })
+}
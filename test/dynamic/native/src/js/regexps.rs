@@ -0,0 +1,21 @@
+use neon::vm::{FunctionContext, JsResult, Context};
+use neon::mem::Handle;
+use neon::js::{JsArray, JsString, Object};
+use neon::js::regexp::JsRegExp;
+
+pub fn return_js_regexp(mut cx: FunctionContext) -> JsResult<JsRegExp> {
+    let pattern = cx.argument::<JsString>(0)?.value();
+    let flags = cx.argument::<JsString>(1)?.value();
+    JsRegExp::new(&mut cx, pattern, &flags)
+}
+
+pub fn read_js_regexp(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let regexp: Handle<JsRegExp> = cx.argument(0)?;
+    let source = regexp.source(&mut cx);
+    let flags = regexp.flags();
+    let flags = cx.string(flags);
+    let result = JsArray::new(&mut cx, 2);
+    result.set(&mut cx, 0, source)?;
+    result.set(&mut cx, 1, flags)?;
+    Ok(result)
+}
@@ -1,6 +1,61 @@
-use neon::vm::{FunctionContext, JsResult, Context};
-use neon::js::JsString;
+use neon::vm::{FunctionContext, JsResult, Context, JsResultExt};
+use neon::js::{JsString, JsNumber, JsArray, JsValue, JsNull, Value};
+use neon::js::error::{JsError, Kind};
+use neon::mem::Handle;
 
 pub fn return_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(cx.string("hello node"))
 }
+
+pub fn js_string_length(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let s: Handle<JsString> = cx.argument(0)?;
+    Ok(cx.number(s.length() as f64))
+}
+
+pub fn reverse_js_string_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s: Handle<JsString> = cx.argument(0)?;
+    let mut units = s.to_utf16();
+    units.reverse();
+    JsString::from_utf16(&mut cx, &units).unwrap_or_throw(&mut cx)
+}
+
+pub fn return_js_string_from_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    JsString::from_latin1(&mut cx, b"caf\xe9").unwrap_or_throw(&mut cx)
+}
+
+pub fn js_string_latin1_bytes(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let s: Handle<JsString> = cx.argument(0)?;
+    match s.to_latin1() {
+        Some(bytes) => Ok(JsArray::from_slice(&mut cx, &bytes)?.upcast()),
+        None => Ok(JsNull::new().upcast())
+    }
+}
+
+pub fn concat_js_strings(mut cx: FunctionContext) -> JsResult<JsString> {
+    let mut buffer = String::with_capacity(64);
+    for i in 0..cx.len() {
+        let s: Handle<JsString> = cx.argument(i)?;
+        s.value_into(&mut buffer);
+    }
+    Ok(cx.string(buffer))
+}
+
+pub fn check_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s: Handle<JsString> = cx.argument(0)?;
+    match s.try_value() {
+        Ok(value) => Ok(cx.string(value)),
+        Err(_) => {
+            let msg = format!("lone surrogate in {:?}", s.value());
+            JsError::throw(&mut cx, Kind::TypeError, &msg)
+        }
+    }
+}
+
+static GREETING: &'static str = "hello from the binary";
+
+pub fn return_external_js_strings(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let embedded = JsString::external(&mut cx, GREETING).unwrap_or_throw(&mut cx)?;
+    let accented = JsString::external(&mut cx, "héllo").unwrap_or_throw(&mut cx)?;
+    let owned = JsString::external_string(&mut cx, "x".repeat(1000)).unwrap_or_throw(&mut cx)?;
+    JsArray::from_slice(&mut cx, &[embedded, accented, owned])
+}
@@ -1,7 +1,12 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 use neon::vm::{FunctionContext, TaskContext, JsResult, Context};
-use neon::js::{JsUndefined, JsNumber, JsFunction};
+use neon::js::{JsUndefined, JsNumber, JsFunction, JsObject};
+use neon::js::boxed::JsBox;
 use neon::js::error::{Kind, JsError};
-use neon::task::Task;
+use neon::mem::Handle;
+use neon::task::{self, Task, CancelHandle};
 
 struct SuccessTask;
 
@@ -46,3 +51,89 @@ pub fn perform_failing_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     FailureTask.schedule(f);
     Ok(cx.undefined())
 }
+
+// A task that runs for up to the given time, stopping early if it's cancelled.
+struct SpinTask(u64);
+
+impl Task for SpinTask {
+    type Output = f64;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(self.0) {
+            if task::is_cancelled() {
+                return Err(format!("cancelled"));
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        Ok(self.0 as f64)
+    }
+
+    fn complete(self, mut cx: TaskContext, result: Result<Self::Output, Self::Error>) -> JsResult<Self::JsEvent> {
+        match result {
+            Ok(ms) => Ok(cx.number(ms)),
+            Err(msg) => JsError::throw(&mut cx, Kind::Error, &msg)
+        }
+    }
+}
+
+// The number of times a `CountedTask` has been performed.
+static COUNTED_TASKS_PERFORMED: AtomicUsize = AtomicUsize::new(0);
+
+// A task that only records that it was performed, to check whether cancelling it took effect.
+struct CountedTask;
+
+impl Task for CountedTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        COUNTED_TASKS_PERFORMED.fetch_add(1, Ordering::SeqCst);
+        Ok(17)
+    }
+
+    fn complete(self, mut cx: TaskContext, result: Result<Self::Output, Self::Error>) -> JsResult<Self::JsEvent> {
+        Ok(cx.number(result.unwrap()))
+    }
+}
+
+pub fn perform_cancellable_task(mut cx: FunctionContext) -> JsResult<JsBox<CancelHandle>> {
+    let ms = cx.argument::<JsNumber>(0)?.value() as u64;
+    let f = cx.argument::<JsFunction>(1)?;
+    let handle = SpinTask(ms).schedule_cancellable(f);
+    JsBox::new(&mut cx, handle)
+}
+
+pub fn perform_counted_task(mut cx: FunctionContext) -> JsResult<JsBox<CancelHandle>> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let handle = CountedTask.schedule_cancellable(f);
+    JsBox::new(&mut cx, handle)
+}
+
+pub fn perform_counted_task_with_signal(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let signal: Handle<JsObject> = cx.argument(0)?;
+    let f = cx.argument::<JsFunction>(1)?;
+    CountedTask.schedule_with_signal(&mut cx, f, signal)?;
+    Ok(cx.undefined())
+}
+
+pub fn counted_tasks_performed(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(COUNTED_TASKS_PERFORMED.load(Ordering::SeqCst) as f64))
+}
+
+pub fn cancel_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let handle = cx.argument::<JsBox<CancelHandle>>(0)?;
+    handle.cancel();
+    Ok(cx.undefined())
+}
+
+pub fn perform_task_with_signal(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let ms = cx.argument::<JsNumber>(0)?.value() as u64;
+    let signal: Handle<JsObject> = cx.argument(1)?;
+    let f = cx.argument::<JsFunction>(2)?;
+    SpinTask(ms).schedule_with_signal(&mut cx, f, signal)?;
+    Ok(cx.undefined())
+}
@@ -0,0 +1,124 @@
+use neon::vm::{FunctionContext, JsResult, Context};
+use neon::mem::Handle;
+use neon::js::binary::JsBuffer;
+use neon::js::{JsArray, JsBoolean, JsFunction, JsNumber, JsObject, JsString, JsType, JsValue, JsVariant, Object, PrimitiveHint, Value};
+
+pub fn compare_values(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let a: Handle<JsValue> = cx.argument(0)?;
+    let b: Handle<JsValue> = cx.argument(1)?;
+    let strict = a.strict_equals(b);
+    let loose = a.loose_equals(&mut cx, b)?;
+    let same = a.same_value(b);
+    let result = JsArray::new(&mut cx, 3);
+    let strict = cx.boolean(strict);
+    let loose = cx.boolean(loose);
+    let same = cx.boolean(same);
+    result.set(&mut cx, 0, strict)?;
+    result.set(&mut cx, 1, loose)?;
+    result.set(&mut cx, 2, same)?;
+    Ok(result)
+}
+
+pub fn type_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let name = match v.type_of() {
+        JsType::Undefined => "undefined",
+        JsType::Object => "object",
+        JsType::Boolean => "boolean",
+        JsType::Number => "number",
+        JsType::String => "string",
+        JsType::Symbol => "symbol",
+        JsType::Function => "function",
+        JsType::BigInt => "bigint"
+    };
+    Ok(cx.string(name))
+}
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let ctor: Handle<JsFunction> = cx.argument(1)?;
+    let b = v.instance_of(&mut cx, ctor)?;
+    Ok(cx.boolean(b))
+}
+
+pub fn convert_to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    v.to_number(&mut cx)
+}
+
+pub fn convert_to_boolean(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    v.to_boolean(&mut cx)
+}
+
+pub fn convert_to_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    v.to_object(&mut cx)
+}
+
+pub fn convert_to_integers(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let integer = v.to_integer(&mut cx)?;
+    let int32 = v.to_int32(&mut cx)?;
+    let uint32 = v.to_uint32(&mut cx)?;
+    let result = JsArray::new(&mut cx, 3);
+    result.set(&mut cx, 0, integer)?;
+    result.set(&mut cx, 1, int32)?;
+    result.set(&mut cx, 2, uint32)?;
+    Ok(result)
+}
+
+pub fn convert_to_primitive(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let hint = cx.argument::<JsString>(1)?.value();
+    let hint = match &hint[..] {
+        "number" => PrimitiveHint::Number,
+        "string" => PrimitiveHint::String,
+        _ => PrimitiveHint::Default
+    };
+    v.to_primitive(&mut cx, hint)
+}
+
+pub fn variant_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let name = match v.variant() {
+        JsVariant::Undefined(_) => "undefined",
+        JsVariant::Null(_) => "null",
+        JsVariant::Boolean(_) => "boolean",
+        JsVariant::Number(_) => "number",
+        JsVariant::String(_) => "string",
+        JsVariant::Array(_) => "array",
+        JsVariant::Function(_) => "function",
+        JsVariant::Buffer(_) => "buffer",
+        JsVariant::ArrayBuffer(_) => "arraybuffer",
+        JsVariant::Error(_) => "error",
+        JsVariant::Object(_) => "object",
+        JsVariant::Other(_) => "other"
+    };
+    Ok(cx.string(name))
+}
+
+pub fn parse_json(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let text = cx.argument::<JsString>(0)?.value();
+    cx.json_parse(&text)
+}
+
+pub fn stringify_json(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    let indent = cx.argument::<JsNumber>(1)?.value() as usize;
+    cx.json_stringify(value, indent)
+}
+
+pub fn serialize_value(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    let bytes = neon::serialize(&mut cx, value)?;
+    let mut buffer = JsBuffer::new(&mut cx, bytes.len() as u32)?;
+    cx.borrow_mut(&mut buffer, |data| data.as_mut_slice::<u8>().copy_from_slice(&bytes));
+    Ok(buffer)
+}
+
+pub fn deserialize_value(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let buffer: Handle<JsBuffer> = cx.argument(0)?;
+    let bytes = cx.borrow(&buffer, |data| data.as_slice::<u8>().to_vec());
+    neon::deserialize(&mut cx, &bytes)
+}
@@ -2,25 +2,60 @@
 extern crate neon;
 
 mod js {
+    pub mod values;
     pub mod strings;
+    pub mod regexps;
     pub mod numbers;
     pub mod arrays;
     pub mod objects;
+    pub mod collections;
     pub mod functions;
     pub mod classes;
+    pub mod boxes;
     pub mod tasks;
+    pub mod promises;
 }
 
-use js::strings::return_js_string;
+use js::values::*;
+use js::strings::*;
+use js::regexps::*;
 use js::numbers::*;
 use js::arrays::*;
 use js::objects::*;
+use js::collections::*;
 use js::functions::*;
 use js::classes::*;
+use js::boxes::*;
 use js::tasks::*;
+use js::promises::*;
+use neon::serialize::Serializable;
 
 register_module!(mut cx, {
+    cx.export_function("compare_values", compare_values)?;
+    cx.export_function("type_of", type_of)?;
+    cx.export_function("instance_of", instance_of)?;
+    cx.export_function("convert_to_number", convert_to_number)?;
+    cx.export_function("convert_to_boolean", convert_to_boolean)?;
+    cx.export_function("convert_to_object", convert_to_object)?;
+    cx.export_function("convert_to_integers", convert_to_integers)?;
+    cx.export_function("convert_to_primitive", convert_to_primitive)?;
+    cx.export_function("variant_of", variant_of)?;
+    cx.export_function("parse_json", parse_json)?;
+    cx.export_function("stringify_json", stringify_json)?;
+    cx.export_function("serialize_value", serialize_value)?;
+    cx.export_function("deserialize_value", deserialize_value)?;
+
     cx.export_function("return_js_string", return_js_string)?;
+    cx.export_function("js_string_length", js_string_length)?;
+    cx.export_function("reverse_js_string_utf16", reverse_js_string_utf16)?;
+    cx.export_function("return_js_string_from_latin1", return_js_string_from_latin1)?;
+    cx.export_function("js_string_latin1_bytes", js_string_latin1_bytes)?;
+    cx.export_function("concat_js_strings", concat_js_strings)?;
+    cx.export_function("check_js_string", check_js_string)?;
+    cx.export_function("return_external_js_strings", return_external_js_strings)?;
+
+    cx.export_function("return_js_regexp", return_js_regexp)?;
+    cx.export_function("read_js_regexp", read_js_regexp)?;
 
     cx.export_function("return_js_number", return_js_number)?;
     cx.export_function("return_large_js_number", return_large_js_number)?;
@@ -31,16 +66,47 @@ register_module!(mut cx, {
     cx.export_function("accept_and_return_large_js_number", accept_and_return_large_js_number)?;
     cx.export_function("accept_and_return_float_js_number", accept_and_return_float_js_number)?;
     cx.export_function("accept_and_return_negative_js_number", accept_and_return_negative_js_number)?;
+    cx.export_function("return_js_integers", return_js_integers)?;
+    cx.export_function("classify_js_number", classify_js_number)?;
+    cx.export_function("convert_js_number", convert_js_number)?;
 
     cx.export_function("return_js_array", return_js_array)?;
     cx.export_function("return_js_array_with_number", return_js_array_with_number)?;
     cx.export_function("return_js_array_with_string", return_js_array_with_string)?;
+    cx.export_function("return_js_array_from_iter", return_js_array_from_iter)?;
+    cx.export_function("return_js_array_from_slice", return_js_array_from_slice)?;
+    cx.export_function("push_to_js_array", push_to_js_array)?;
+    cx.export_function("sum_js_array", sum_js_array)?;
+    cx.export_function("join_js_array", join_js_array)?;
+    cx.export_function("return_js_arrays_from_numeric_slices", return_js_arrays_from_numeric_slices)?;
+    cx.export_function("double_f64_js_array", double_f64_js_array)?;
+    cx.export_function("negate_i32_js_array", negate_i32_js_array)?;
+    cx.export_function("sum_u32_js_array", sum_u32_js_array)?;
+    cx.export_function("copy_js_array_by_element", copy_js_array_by_element)?;
+    cx.export_function("copy_js_array_in_bulk", copy_js_array_in_bulk)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
     cx.export_function("return_js_object", return_js_object)?;
     cx.export_function("return_js_object_with_number", return_js_object_with_number)?;
     cx.export_function("return_js_object_with_string", return_js_object_with_string)?;
     cx.export_function("return_js_object_with_mixed_content", return_js_object_with_mixed_content)?;
+    cx.export_function("has_properties", has_properties)?;
+    cx.export_function("delete_property", delete_property)?;
+    cx.export_function("define_constant", define_constant)?;
+    cx.export_function("define_hidden_accessor", define_hidden_accessor)?;
+    cx.export_function("describe_property", describe_property)?;
+    cx.export_function("create_with_prototype", create_with_prototype)?;
+    cx.export_function("get_prototype", get_prototype)?;
+    cx.export_function("set_prototype", set_prototype)?;
+    cx.export_function("restrict_object", restrict_object)?;
+    cx.export_function("integrity_of", integrity_of)?;
+    cx.export_function("list_properties", list_properties)?;
+    cx.export_function("return_object_with_accessors", return_object_with_accessors)?;
+    cx.export_function("return_object_from_entries", return_object_from_entries)?;
+    cx.export_function("return_records_with_shape", return_records_with_shape)?;
+    cx.export_function("build_shape_with_missing_value", build_shape_with_missing_value)?;
+    cx.export_function("increment_count", increment_count)?;
+    cx.export_function("return_interned_names", return_interned_names)?;
     cx.export_function("return_array_buffer", return_array_buffer)?;
     cx.export_function("read_array_buffer_with_lock", read_array_buffer_with_lock)?;
     cx.export_function("read_array_buffer_with_borrow", read_array_buffer_with_borrow)?;
@@ -51,6 +117,20 @@ register_module!(mut cx, {
     cx.export_function("write_buffer_with_lock", write_buffer_with_lock)?;
     cx.export_function("write_buffer_with_borrow_mut", write_buffer_with_borrow_mut)?;
 
+    cx.export_function("return_js_map_with_object_key", return_js_map_with_object_key)?;
+    cx.export_function("read_js_map_with_object_key", read_js_map_with_object_key)?;
+    cx.export_function("delete_from_js_map", delete_from_js_map)?;
+    cx.export_function("js_map_entries", js_map_entries)?;
+    cx.export_function("clear_js_map", clear_js_map)?;
+    cx.export_function("return_js_set", return_js_set)?;
+    cx.export_function("js_set_values", js_set_values)?;
+    cx.export_function("collect_iterable", collect_iterable)?;
+    cx.export_function("sum_iterable", sum_iterable)?;
+    cx.export_function("return_rust_iterator", return_rust_iterator)?;
+    cx.export_function("return_infinite_rust_iterator", return_infinite_rust_iterator)?;
+    cx.export_function("delete_from_js_set", delete_from_js_set)?;
+    cx.export_function("is_js_map_or_set", is_js_map_or_set)?;
+
     cx.export_function("return_js_function", return_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
     cx.export_function("construct_js_function", construct_js_function)?;
@@ -66,11 +146,34 @@ register_module!(mut cx, {
 
     cx.export_function("perform_async_task", perform_async_task)?;
     cx.export_function("perform_failing_task", perform_failing_task)?;
+    cx.export_function("perform_cancellable_task", perform_cancellable_task)?;
+    cx.export_function("perform_counted_task", perform_counted_task)?;
+    cx.export_function("perform_counted_task_with_signal", perform_counted_task_with_signal)?;
+    cx.export_function("counted_tasks_performed", counted_tasks_performed)?;
+    cx.export_function("cancel_task", cancel_task)?;
+    cx.export_function("perform_task_with_signal", perform_task_with_signal)?;
+
+    cx.export_function("await_promise", await_promise)?;
+    cx.export_function("is_promise", is_promise)?;
+    cx.export_function("promise_to_future", promise_to_future)?;
+    cx.export_function("poll_promise_future", poll_promise_future)?;
+    cx.export_async_function("delay_number", delay_number)?;
+    cx.export_function("delay_object", delay_object)?;
+    cx.export_function("panic_in_future", panic_in_future)?;
+    cx.export_function("throw_after_future", throw_after_future)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
 
+    cx.export_function("box_person", box_person)?;
+    cx.export_function("person_name", person_name)?;
+    cx.export_function("box_counter", box_counter)?;
+    cx.export_function("increment_counter", increment_counter)?;
+    cx.export_function("is_boxed_person", is_boxed_person)?;
+    cx.export_function("require_boxed_person", require_boxed_person)?;
+
     cx.export_class::<JsUser>("User")?;
+    JsUser::register(&mut cx, "User");
     cx.export_class::<JsPanickyAllocator>("PanickyAllocator")?;
     cx.export_class::<JsPanickyConstructor>("PanickyConstructor")?;
 